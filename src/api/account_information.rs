@@ -1,6 +1,6 @@
-use serde::Deserialize;
-use serde_repr::Deserialize_repr;
+use serde::{Deserialize, Serialize};
 
+use crate::api::ids::UserId;
 use crate::api::routes::RobloxApi;
 use crate::errors::RoboltError;
 use crate::utils::client::Authenticated;
@@ -8,19 +8,19 @@ use crate::utils::response::CountResponse;
 use crate::Robolt;
 
 impl<State> Robolt<State> {
-	pub async fn roblox_badges(&self, user_id: u64) -> Result<Vec<RobloxBadge>, RoboltError> {
+	pub async fn roblox_badges(&self, user_id: impl Into<UserId>) -> Result<Vec<RobloxBadge>, RoboltError> {
 		self.request(
 			RobloxApi::AccountInformation,
-			format!("/v1/users/{user_id}/roblox-badges"),
+			format!("/v1/users/{user_id}/roblox-badges", user_id = user_id.into()),
 		)
 		.send()
 		.await
 	}
 
-	pub async fn user_socials(&self, user_id: u64) -> Result<UserSocials, RoboltError> {
+	pub async fn user_socials(&self, user_id: impl Into<UserId>) -> Result<UserSocials, RoboltError> {
 		self.request(
 			RobloxApi::AccountInformation,
-			format!("/v1/users/{user_id}/promotion-channels"),
+			format!("/v1/users/{user_id}/promotion-channels", user_id = user_id.into()),
 		)
 		.send()
 		.await
@@ -66,12 +66,14 @@ impl Robolt<Authenticated> {
 	}
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize_repr)]
-#[repr(u8)]
-pub enum Gender {
-	Unknown = 1,
-	Male = 2,
-	Female = 3,
+int_enum! {
+	#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+	#[serde(from = "u8", into = "u8")]
+	pub enum Gender: u8, unknown = Other {
+		Unknown = 1,
+		Male = 2,
+		Female = 3,
+	}
 }
 
 #[derive(Deserialize)]
@@ -84,7 +86,7 @@ struct ProfileDescription {
 	description: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PhoneNumber {
 	pub country_code: String,
@@ -95,7 +97,7 @@ pub struct PhoneNumber {
 	pub can_bypass_password_for_phone_update: bool,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RobloxBadge {
 	pub id: u8,
@@ -104,7 +106,7 @@ pub struct RobloxBadge {
 	pub image_url: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UserSocials {
 	pub facebook: Option<String>,
@@ -114,7 +116,7 @@ pub struct UserSocials {
 	pub guilded: Option<String>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AuthenticatedUserSocials {
 	#[serde(rename = "promotionChannelsVisibilityPrivacy")]
@@ -123,16 +125,19 @@ pub struct AuthenticatedUserSocials {
 	pub connections: UserSocials,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum SocialsVisibility {
 	AllUsers,
 	FriendsFollowingAndFollowers,
 	FriendsAndFollowing,
 	Friends,
 	NoOne,
+	#[serde(untagged)]
+	/// A value unknown to this version of the crate
+	Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Birthdate {
 	#[serde(rename = "birthYear")]
 	pub year: u16,
@@ -140,4 +145,4 @@ pub struct Birthdate {
 	pub month: u8,
 	#[serde(rename = "birthDay")]
 	pub day: u8,
-}
\ No newline at end of file
+}
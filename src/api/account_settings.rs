@@ -1,4 +1,4 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use crate::api::routes::RobloxApi;
 use crate::api::users::PartialUser;
@@ -49,16 +49,19 @@ struct TradeValueResponse {
 	trade_value: TradeValue,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum TradeValue {
 	High,
 	Medium,
 	Low,
 	None,
 	Undefined,
+	#[serde(untagged)]
+	/// A value unknown to this version of the crate
+	Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Email {
 	pub email_address: String,
@@ -66,7 +69,7 @@ pub struct Email {
 	pub can_bypass_password_for_email_update: bool,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BlockedUsers {
 	pub blocked_users: Vec<PartialUser>,
@@ -86,7 +89,7 @@ struct PrivacySettingResponse {
 	value: PrivacyState,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum PrivacySetting {
 	AppChat,
 	GameChat,
@@ -95,9 +98,9 @@ pub enum PrivacySetting {
 	PrivateMessage,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum PrivacyState {
-	#[serde(alias = "All", alias = "AllUsers")]
+	#[serde(rename = "AllUsers", alias = "All")]
 	Everyone,
 	AllAuthenticatedUsers,
 	FriendsFollowingAndFollowers,
@@ -109,4 +112,7 @@ pub enum PrivacyState {
 	NoOne,
 	Disabled,
 	Undefined,
-}
\ No newline at end of file
+	#[serde(untagged)]
+	/// A value unknown to this version of the crate
+	Unknown(String),
+}
@@ -1,14 +1,24 @@
-use std::error::Error;
-use std::intrinsics::type_name;
-use std::marker::PhantomData;
+use std::any::type_name;
+use std::time::SystemTime;
 
-use reqwest::header::{CONTENT_LENGTH, COOKIE};
+use reqwest::header::HeaderValue;
 use reqwest::StatusCode;
 
 use crate::api::routes::RobloxApi;
-use crate::utils::client::{default_client_headers, Authenticated, Unauthenticated};
+use crate::api::users::PartialUser;
+use crate::errors::{LoginError, RoboltError};
+use crate::utils::client::{Authenticated, Unauthenticated};
+use crate::utils::session::SessionInfo;
 use crate::Robolt;
 
+/// Requested without a CSRF token when logging in to obtain one, which Roblox
+/// rejects before processing (validating a username has no side effects
+/// either way)
+const XCSRF_PATH: &str = "/v1/usernames/validate";
+
+/// Words Roblox uses in the errors returned for locked or moderated accounts
+const LOCKED_KEYWORDS: [&str; 3] = ["locked", "moderated", "banned"];
+
 impl<State> Robolt<State> {
 	pub fn is_authenticated(&self) -> bool {
 		type_name::<State>() == type_name::<Authenticated>()
@@ -16,42 +26,75 @@ impl<State> Robolt<State> {
 }
 
 impl Robolt<Unauthenticated> {
-	pub async fn set_cookie(self, roblox_cookie: String) -> Result<Robolt<Authenticated>, Box<dyn Error>> {
+	/// Authenticates the client with a `.ROBLOSECURITY` cookie, verified by
+	/// fetching the user it belongs to
+	///
+	/// A CSRF token is obtained as well, so that the first mutating request is
+	/// not rejected for lacking one.
+	///
+	/// ### Arguments
+	/// * `roblox_cookie` - The value of the `.ROBLOSECURITY` cookie
+	pub async fn login(self, roblox_cookie: String) -> Result<(Robolt<Authenticated>, PartialUser), RoboltError> {
 		let cookie = format!(".ROBLOSECURITY={roblox_cookie}");
-		let mut headers = default_client_headers();
 
-		headers.insert(COOKIE, cookie.parse()?);
-		headers.insert(CONTENT_LENGTH, "0".parse()?);
+		if roblox_cookie.is_empty() || roblox_cookie.contains(';') || HeaderValue::from_str(&cookie).is_err() {
+			return Err(RoboltError::Login(LoginError::Invalid));
+		}
 
-		let res = self
-			.http
-			.post(format!("https://{}/v2/logout", RobloxApi::Auth.url()))
-			.headers(headers.clone())
-			.send()
-			.await?;
+		let mut client = self.into_state::<Authenticated>(Some(cookie), None);
 
-		if !res.status().is_success() && res.status() != StatusCode::FORBIDDEN {
-			return Err("Invalid cookie".into());
-		}
+		let user = match client.me().await {
+			Ok(user) => user,
+			Err(RoboltError::Unauthorized {
+				..
+			}) => return Err(RoboltError::Login(LoginError::Expired)),
+			Err(err) if is_locked(&err) => {
+				return Err(RoboltError::Login(LoginError::Locked {
+					errors: err.api_errors().to_vec(),
+				}))
+			},
+			Err(err) => return Err(err),
+		};
 
-		let csrf_token = res.headers().get("x-csrf-token").ok_or("No CSRF token found")?;
+		client.fetch_xcsrf(RobloxApi::Auth, XCSRF_PATH).await?;
+		client.session_info = Some(SessionInfo {
+			user_id: user.id,
+			created_at: SystemTime::now(),
+		});
 
-		Ok(Robolt {
-			state: PhantomData::<Authenticated>,
-			http: self.http,
-			cookie: Some(cookie),
-			xcsrf: Some(csrf_token.to_str()?.to_string()),
-		})
+		Ok((client, user))
+	}
+
+	/// Authenticates the client with a `.ROBLOSECURITY` cookie, discarding the
+	/// identity returned by [Robolt::login]
+	///
+	/// ### Arguments
+	/// * `roblox_cookie` - The value of the `.ROBLOSECURITY` cookie
+	pub async fn set_cookie(self, roblox_cookie: String) -> Result<Robolt<Authenticated>, RoboltError> {
+		self.login(roblox_cookie).await.map(|(client, _)| client)
 	}
 }
 
 impl Robolt<Authenticated> {
 	pub fn remove_cookie(self) -> Robolt<Unauthenticated> {
-		Robolt {
-			state: PhantomData::<Unauthenticated>,
-			http: self.http,
-			cookie: None,
-			xcsrf: None,
-		}
+		self.into_state(None, None)
 	}
-}
\ No newline at end of file
+}
+
+/// Whether Roblox rejected the cookie because its account is locked or
+/// moderated, as opposed to another 403 such as a challenge
+fn is_locked(err: &RoboltError) -> bool {
+	let RoboltError::Api {
+		status,
+		errors,
+	} = err
+	else {
+		return false;
+	};
+
+	*status == StatusCode::FORBIDDEN &&
+		errors.iter().any(|error| {
+			let message = error.message.to_lowercase();
+			LOCKED_KEYWORDS.iter().any(|keyword| message.contains(keyword))
+		})
+}
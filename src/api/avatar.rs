@@ -2,8 +2,8 @@ use std::collections::HashMap;
 
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
-use serde_repr::{Deserialize_repr, Serialize_repr};
 
+use crate::api::ids::{AssetId, OutfitId, UniverseId, UserId};
 use crate::api::routes::RobloxApi;
 use crate::api::Limit;
 use crate::errors::RoboltError;
@@ -16,16 +16,23 @@ impl Robolt<Authenticated> {
 		self.request(RobloxApi::Avatar, "/v1/avatar").send().await
 	}
 
-	pub async fn add_wearing_asset(&self, asset_id: u64) -> Result<(), RoboltError> {
-		self.request(RobloxApi::Avatar, format!("/v1/avatar/assets/{asset_id}/wear"))
-			.method(Method::POST)
-			.send::<EmptyResponse>()
-			.await?;
+	pub async fn add_wearing_asset(&self, asset_id: impl Into<AssetId>) -> Result<(), RoboltError> {
+		self.request(
+			RobloxApi::Avatar,
+			format!("/v1/avatar/assets/{asset_id}/wear", asset_id = asset_id.into()),
+		)
+		.method(Method::POST)
+		.send::<EmptyResponse>()
+		.await?;
 
 		Ok(())
 	}
 
-	pub async fn set_wearing_assets(&self, asset_ids: Vec<u64>) -> Result<InvalidAssets, RoboltError> {
+	pub async fn set_wearing_assets(
+		&self,
+		asset_ids: Vec<impl Into<AssetId>>,
+	) -> Result<InvalidAssets, RoboltError> {
+		let asset_ids = asset_ids.into_iter().map(Into::into).collect::<Vec<AssetId>>();
 		let body = HashMap::from([("assetIds", asset_ids)]);
 
 		self.request(RobloxApi::Avatar, "/v1/avatar/set-wearing-assets")
@@ -34,11 +41,14 @@ impl Robolt<Authenticated> {
 			.await
 	}
 
-	pub async fn remove_wearing_asset(&self, asset_id: u64) -> Result<(), RoboltError> {
-		self.request(RobloxApi::Avatar, format!("/v1/avatar/assets/{asset_id}/remove"))
-			.method(Method::POST)
-			.send::<EmptyResponse>()
-			.await?;
+	pub async fn remove_wearing_asset(&self, asset_id: impl Into<AssetId>) -> Result<(), RoboltError> {
+		self.request(
+			RobloxApi::Avatar,
+			format!("/v1/avatar/assets/{asset_id}/remove", asset_id = asset_id.into()),
+		)
+		.method(Method::POST)
+		.send::<EmptyResponse>()
+		.await?;
 
 		Ok(())
 	}
@@ -53,7 +63,7 @@ impl Robolt<Authenticated> {
 	}
 
 	pub async fn set_avatar_type(&self, avatar_type: BodyType) -> Result<(), RoboltError> {
-		let body = HashMap::from([("playerAvatarType", avatar_type as u8)]);
+		let body = HashMap::from([("playerAvatarType", avatar_type)]);
 
 		self.request(RobloxApi::Avatar, "/v1/avatar/set-player-avatar-type")
 			.method(Method::POST)
@@ -81,20 +91,26 @@ impl Robolt<Authenticated> {
 		Ok(())
 	}
 
-	pub async fn delete_outfit(&self, outfit_id: u64) -> Result<(), RoboltError> {
-		self.request(RobloxApi::Avatar, format!("/v1/outfits/{outfit_id}/delete"))
-			.method(Method::POST)
-			.send::<EmptyResponse>()
-			.await?;
+	pub async fn delete_outfit(&self, outfit_id: impl Into<OutfitId>) -> Result<(), RoboltError> {
+		self.request(
+			RobloxApi::Avatar,
+			format!("/v1/outfits/{outfit_id}/delete", outfit_id = outfit_id.into()),
+		)
+		.method(Method::POST)
+		.send::<EmptyResponse>()
+		.await?;
 
 		Ok(())
 	}
 
-	pub async fn wear_outfit(&self, outfit_id: u64) -> Result<InvalidAssets, RoboltError> {
-		self.request(RobloxApi::Avatar, format!("/v1/outfits/{outfit_id}/wear"))
-			.method(Method::POST)
-			.send()
-			.await
+	pub async fn wear_outfit(&self, outfit_id: impl Into<OutfitId>) -> Result<InvalidAssets, RoboltError> {
+		self.request(
+			RobloxApi::Avatar,
+			format!("/v1/outfits/{outfit_id}/wear", outfit_id = outfit_id.into()),
+		)
+		.method(Method::POST)
+		.send()
+		.await
 	}
 
 	pub async fn create_outfit(&self, new_outfit: OutfitV1) -> Result<(), RoboltError> {
@@ -106,11 +122,18 @@ impl Robolt<Authenticated> {
 		Ok(())
 	}
 
-	pub async fn update_outfit(&self, outfit_id: u64, updated_outfit: OutfitV2) -> Result<(), RoboltError> {
-		self.request(RobloxApi::Avatar, format!("/v2/outfits/{outfit_id}/update"))
-			.method(Method::POST)
-			.send_body::<_, EmptyResponse>(updated_outfit)
-			.await?;
+	pub async fn update_outfit(
+		&self,
+		outfit_id: impl Into<OutfitId>,
+		updated_outfit: OutfitV2,
+	) -> Result<(), RoboltError> {
+		self.request(
+			RobloxApi::Avatar,
+			format!("/v2/outfits/{outfit_id}/update", outfit_id = outfit_id.into()),
+		)
+		.method(Method::POST)
+		.send_body::<_, EmptyResponse>(updated_outfit)
+		.await?;
 
 		Ok(())
 	}
@@ -131,43 +154,58 @@ impl<State> Robolt<State> {
 		self.request(RobloxApi::Avatar, "/v1/avatar/metadata").send().await
 	}
 
-	pub async fn avatar(&self, user_id: u64) -> Result<Avatar, RoboltError> {
-		self.request(RobloxApi::Avatar, format!("/v1/users/{user_id}/avatar"))
-			.send()
-			.await
+	pub async fn avatar(&self, user_id: impl Into<UserId>) -> Result<Avatar, RoboltError> {
+		self.request(
+			RobloxApi::Avatar,
+			format!("/v1/users/{user_id}/avatar", user_id = user_id.into()),
+		)
+		.send()
+		.await
 	}
 
-	pub async fn currently_wearing(&self, user_id: u64) -> Result<Vec<u64>, RoboltError> {
-		self.request(RobloxApi::Avatar, format!("/v1/users/{user_id}/currently-wearing"))
-			.send::<AssetIdsResponse>()
-			.await
-			.map(|res| res.asset_ids)
+	pub async fn currently_wearing(&self, user_id: impl Into<UserId>) -> Result<Vec<AssetId>, RoboltError> {
+		self.request(
+			RobloxApi::Avatar,
+			format!("/v1/users/{user_id}/currently-wearing", user_id = user_id.into()),
+		)
+		.send::<AssetIdsResponse>()
+		.await
+		.map(|res| res.asset_ids)
 	}
 
-	pub fn outfits(&self, user_id: u64) -> OutfitFilterBuilder<State> {
+	pub fn outfits(&self, user_id: impl Into<UserId>) -> OutfitFilterBuilder<'_, State> {
 		OutfitFilterBuilder::new(user_id, self)
 	}
 
-	pub async fn game_start_info(&self, universe_id: u64) -> Result<GameStartAvatarInfo, RoboltError> {
+	pub async fn game_start_info(
+		&self,
+		universe_id: impl Into<UniverseId>,
+	) -> Result<GameStartAvatarInfo, RoboltError> {
 		self.request(
 			RobloxApi::Avatar,
-			format!("/v1/game-start-info?universeId={universe_id}",),
+			format!(
+				"/v1/game-start-info?universeId={universe_id}",
+				universe_id = universe_id.into(),
+			),
 		)
 		.send()
 		.await
 	}
 
-	pub async fn outfit(&self, outfit_id: u64) -> Result<DetailedOutfit, RoboltError> {
-		self.request(RobloxApi::Avatar, format!("/v1/outfits/{outfit_id}/details"))
-			.send()
-			.await
+	pub async fn outfit(&self, outfit_id: impl Into<OutfitId>) -> Result<DetailedOutfit, RoboltError> {
+		self.request(
+			RobloxApi::Avatar,
+			format!("/v1/outfits/{outfit_id}/details", outfit_id = outfit_id.into()),
+		)
+		.send()
+		.await
 	}
 }
 
 impl<'a, State> OutfitFilterBuilder<'a, State> {
-	pub fn new(user_id: u64, client: &'a Robolt<State>) -> Self {
+	pub fn new(user_id: impl Into<UserId>, client: &'a Robolt<State>) -> Self {
 		Self {
-			user_id,
+			user_id: user_id.into(),
 			page: 1,
 			items_per_page: Limit::default(),
 			is_editable: true,
@@ -204,10 +242,10 @@ impl<'a, State> OutfitFilterBuilder<'a, State> {
 	}
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct DetailedOutfit {
-	pub id: u64,
+	pub id: OutfitId,
 	#[serde(rename = "playerAvatarType")]
 	pub avatar_type: BodyType,
 	pub name: String,
@@ -225,7 +263,7 @@ pub struct OutfitV1 {
 	pub avatar_type: BodyType,
 	pub name: &'static str,
 	pub body_colors: BodyColors,
-	pub asset_ids: Vec<u64>,
+	pub asset_ids: Vec<AssetId>,
 	pub scale: BodyScale,
 	pub outfit_type: OutfitType,
 }
@@ -247,31 +285,34 @@ pub enum OutfitType {
 	Invalid,
 	Avatar,
 	DynamicHead,
+	#[serde(untagged)]
+	/// A value unknown to this version of the crate
+	Unknown(String),
 }
 
 #[derive(Deserialize)]
 #[serde(rename_all = "camelCase")]
 struct AssetIdsResponse {
-	asset_ids: Vec<u64>,
+	asset_ids: Vec<AssetId>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct InvalidAssets {
 	pub invalid_assets: Vec<AvatarAsset>,
-	pub invalid_asset_ids: Vec<u64>,
+	pub invalid_asset_ids: Vec<AssetId>,
 	pub success: bool,
 }
 
 pub struct OutfitFilterBuilder<'a, State> {
-	user_id: u64,
+	user_id: UserId,
 	page: u8,
 	items_per_page: Limit,
 	is_editable: bool,
 	client: &'a Robolt<State>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FilteredOutfitResponse {
 	pub filtered_count: u32,
@@ -291,21 +332,24 @@ pub enum AvatarItemFilter {
 	Gears = 6,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum AvatarItemType {
 	Asset,
 	Outfit,
+	#[serde(untagged)]
+	/// A value unknown to this version of the crate
+	Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OutfitInfo {
-	pub id: u64,
+	pub id: OutfitId,
 	pub name: String,
 	pub is_editable: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Avatar {
 	pub player_avatar_type: BodyType,
@@ -339,27 +383,27 @@ pub struct BodyColors {
 	pub left_leg_color_id: BrickColor,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AvatarEmotes {
 	#[serde(rename = "assetName")]
 	pub name: String,
 	#[serde(rename = "assetId")]
-	pub id: u64,
+	pub id: AssetId,
 	pub position: u32,
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AvatarAsset {
-	pub id: u64,
+	pub id: AssetId,
 	pub name: String,
 	pub asset_type: AvatarAssetInfo,
 	pub meta: Option<AvatarAssetMeta>,
 	pub current_version_id: u64,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct RecentAvatarItem {
 	pub id: u64,
@@ -385,11 +429,14 @@ pub struct AvatarAssetMeta {
 #[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum BodyType {
 	#[default]
-	R6 = 1,
-	R15 = 3,
+	R6,
+	R15,
+	#[serde(untagged)]
+	/// A value unknown to this version of the crate
+	Unknown(String),
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AvatarMetadata {
 	pub enable_default_clothing_message: bool,
@@ -413,7 +460,7 @@ pub struct AvatarMetadata {
 	pub is_lccompletely_enabled: bool,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GameStartAvatarInfo {
 	pub game_avatar_type: String,
@@ -428,7 +475,7 @@ pub struct GameStartAvatarInfo {
 	pub moderation_status: Option<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UniverseAvatarAssetOverride {
 	#[serde(rename = "assetID")]
@@ -438,216 +485,218 @@ pub struct UniverseAvatarAssetOverride {
 	pub is_player_choice: bool,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize_repr, Serialize_repr)]
-#[repr(u16)]
-pub enum BrickColor {
-	#[default]
-	White = 1,
-	Grey = 2,
-	LightYellow = 3,
-	BrickYellow = 5,
-	LightGreenMint = 6,
-	LightReddishViolet = 9,
-	PastelBlue = 11,
-	LightOrangeBrown = 12,
-	Nougat = 18,
-	BrightRed = 21,
-	MedReddishViolet = 22,
-	BrightBlue = 23,
-	BrightYellow = 24,
-	EarthOrange = 25,
-	Black = 26,
-	DarkGrey = 27,
-	DarkGreen = 28,
-	MediumGreen = 29,
-	LightYellowishOrange = 36,
-	BrightGreen = 37,
-	DarkOrange = 38,
-	LightBluishViolet = 39,
-	Transparent = 40,
-	TrRed = 41,
-	TrLgBlue = 42,
-	TrBlue = 43,
-	TrYellow = 44,
-	LightBlue = 45,
-	TrFluReddishOrange = 47,
-	TrGreen = 48,
-	TrFluGreen = 49,
-	PhosphWhite = 50,
-	LightRed = 100,
-	MediumRed = 101,
-	MediumBlue = 102,
-	LightGrey = 103,
-	BrightViolet = 104,
-	BrYellowishOrange = 105,
-	BrightOrange = 106,
-	BrightBluishGreen = 107,
-	EarthYellow = 108,
-	BrightBluishViolet = 110,
-	TrBrown = 111,
-	MediumBluishViolet = 112,
-	TrMediReddishViolet = 113,
-	MedYellowishGreen = 115,
-	MedBluishGreen = 116,
-	LightBluishGreen = 118,
-	BrYellowishGreen = 119,
-	LigYellowishGreen = 120,
-	MedYellowishOrange = 121,
-	BrReddishOrange = 123,
-	BrightReddishViolet = 124,
-	LightOrange = 125,
-	TrBrightBluishViolet = 126,
-	LightGold = 127,
-	DarkNougat = 128,
-	Silver = 131,
-	NeonOrange = 133,
-	NeonGreen = 134,
-	SandBlue = 135,
-	SandViolet = 136,
-	MediumOrange = 137,
-	SandYellow = 138,
-	EarthBlue = 140,
-	EarthGreen = 141,
-	TrFluBlue = 143,
-	SandBlueMetallic = 145,
-	SandVioletMetallic = 146,
-	SandYellowMetallic = 147,
-	DarkGreyMetallic = 148,
-	BlackMetallic = 149,
-	LightGreyMetallic = 150,
-	SandGreen = 151,
-	SandRed = 153,
-	DarkRed = 154,
-	TrFluYellow = 157,
-	TrFluRed = 158,
-	GunMetallic = 168,
-	RedFlipFlop = 176,
-	YellowFlipFlop = 178,
-	SilverFlipFlop = 179,
-	Curry = 180,
-	FireYellow = 190,
-	FlameYellowishOrange = 191,
-	ReddishBrown = 192,
-	FlameReddishOrange = 193,
-	MediumStoneGrey = 194,
-	RoyalBlue = 195,
-	DarkRoyalBlue = 196,
-	BrightReddishLilac = 198,
-	DarkStoneGrey = 199,
-	LemonMetallic = 200,
-	LightStoneGrey = 208,
-	DarkCurry = 209,
-	FadedGreen = 210,
-	Turquoise = 211,
-	LightRoyalBlue = 212,
-	MediumRoyalBlue = 213,
-	Rust = 216,
-	Brown = 217,
-	ReddishLilac = 218,
-	Lilac = 219,
-	LightLilac = 220,
-	BrightPurple = 221,
-	LightPurple = 222,
-	LightPink = 223,
-	LightBrickYellow = 224,
-	WarmYellowishOrange = 225,
-	CoolYellow = 226,
-	DoveBlue = 232,
-	MediumLilac = 268,
-	SlimeGreen = 301,
-	SmokyGrey = 302,
-	DarkBlue = 303,
-	ParsleyGreen = 304,
-	SteelBlue = 305,
-	StormBlue = 306,
-	Lapis = 307,
-	DarkIndigo = 308,
-	SeaGreen = 309,
-	Shamrock = 310,
-	Fossil = 311,
-	Mulberry = 312,
-	ForestGreen = 313,
-	CadetBlue = 314,
-	ElectricBlue = 315,
-	Eggplant = 316,
-	Moss = 317,
-	Artichoke = 318,
-	SageGreen = 319,
-	GhostGrey = 320,
-	Lilac2 = 321,
-	Plum = 322,
-	Olivine = 323,
-	LaurelGreen = 324,
-	QuillGrey = 325,
-	Crimson = 327,
-	Mint = 328,
-	BabyBlue = 329,
-	CarnationPink = 330,
-	Persimmon = 331,
-	Maroon = 332,
-	Gold = 333,
-	DaisyOrange = 334,
-	Pearl = 335,
-	Fog = 336,
-	Salmon = 337,
-	Terracotta = 338,
-	Cocoa = 339,
-	Wheat = 340,
-	Buttermilk = 341,
-	Mauve = 342,
-	Sunrise = 343,
-	Tawny = 344,
-	Rust2 = 345,
-	Cashmere = 346,
-	Khaki = 347,
-	LilyWhite = 348,
-	Seashell = 349,
-	Burgundy = 350,
-	Cork = 351,
-	Burlap = 352,
-	Beige = 353,
-	Oyster = 354,
-	PineCone = 355,
-	FawnBrown = 356,
-	HurricaneGrey = 357,
-	CloudyGrey = 358,
-	Linen = 359,
-	Copper = 360,
-	DirtBrown = 361,
-	Bronze = 362,
-	Flint = 363,
-	DarkTaupe = 364,
-	BurntSienna = 365,
-	InstitutionalWhite = 1001,
-	MidGray = 1002,
-	ReallyBlack = 1003,
-	ReallyRed = 1004,
-	DeepOrange = 1005,
-	Alder = 1006,
-	DustyRose = 1007,
-	Olive = 1008,
-	NewYeller = 1009,
-	ReallyBlue = 1010,
-	NavyBlue = 1011,
-	DeepBlue = 1012,
-	Cyan = 1013,
-	CGABrown = 1014,
-	Magenta = 1015,
-	Pink = 1016,
-	DeepOrange2 = 1017,
-	Teal = 1018,
-	Toothpaste = 1019,
-	LimeGreen = 1020,
-	Camo = 1021,
-	Grime = 1022,
-	Lavender = 1023,
-	PastelLightBlue = 1024,
-	PastelOrange = 1025,
-	PastelViolet = 1026,
-	PastelBlueGreen = 1027,
-	PastelGreen = 1028,
-	PastelYellow = 1029,
-	PastelBrown = 1030,
-	RoyalPurple = 1031,
-	HotPink = 1032,
-}
\ No newline at end of file
+int_enum! {
+	#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
+	#[serde(from = "u16", into = "u16")]
+	pub enum BrickColor: u16 {
+		#[default]
+		White = 1,
+		Grey = 2,
+		LightYellow = 3,
+		BrickYellow = 5,
+		LightGreenMint = 6,
+		LightReddishViolet = 9,
+		PastelBlue = 11,
+		LightOrangeBrown = 12,
+		Nougat = 18,
+		BrightRed = 21,
+		MedReddishViolet = 22,
+		BrightBlue = 23,
+		BrightYellow = 24,
+		EarthOrange = 25,
+		Black = 26,
+		DarkGrey = 27,
+		DarkGreen = 28,
+		MediumGreen = 29,
+		LightYellowishOrange = 36,
+		BrightGreen = 37,
+		DarkOrange = 38,
+		LightBluishViolet = 39,
+		Transparent = 40,
+		TrRed = 41,
+		TrLgBlue = 42,
+		TrBlue = 43,
+		TrYellow = 44,
+		LightBlue = 45,
+		TrFluReddishOrange = 47,
+		TrGreen = 48,
+		TrFluGreen = 49,
+		PhosphWhite = 50,
+		LightRed = 100,
+		MediumRed = 101,
+		MediumBlue = 102,
+		LightGrey = 103,
+		BrightViolet = 104,
+		BrYellowishOrange = 105,
+		BrightOrange = 106,
+		BrightBluishGreen = 107,
+		EarthYellow = 108,
+		BrightBluishViolet = 110,
+		TrBrown = 111,
+		MediumBluishViolet = 112,
+		TrMediReddishViolet = 113,
+		MedYellowishGreen = 115,
+		MedBluishGreen = 116,
+		LightBluishGreen = 118,
+		BrYellowishGreen = 119,
+		LigYellowishGreen = 120,
+		MedYellowishOrange = 121,
+		BrReddishOrange = 123,
+		BrightReddishViolet = 124,
+		LightOrange = 125,
+		TrBrightBluishViolet = 126,
+		LightGold = 127,
+		DarkNougat = 128,
+		Silver = 131,
+		NeonOrange = 133,
+		NeonGreen = 134,
+		SandBlue = 135,
+		SandViolet = 136,
+		MediumOrange = 137,
+		SandYellow = 138,
+		EarthBlue = 140,
+		EarthGreen = 141,
+		TrFluBlue = 143,
+		SandBlueMetallic = 145,
+		SandVioletMetallic = 146,
+		SandYellowMetallic = 147,
+		DarkGreyMetallic = 148,
+		BlackMetallic = 149,
+		LightGreyMetallic = 150,
+		SandGreen = 151,
+		SandRed = 153,
+		DarkRed = 154,
+		TrFluYellow = 157,
+		TrFluRed = 158,
+		GunMetallic = 168,
+		RedFlipFlop = 176,
+		YellowFlipFlop = 178,
+		SilverFlipFlop = 179,
+		Curry = 180,
+		FireYellow = 190,
+		FlameYellowishOrange = 191,
+		ReddishBrown = 192,
+		FlameReddishOrange = 193,
+		MediumStoneGrey = 194,
+		RoyalBlue = 195,
+		DarkRoyalBlue = 196,
+		BrightReddishLilac = 198,
+		DarkStoneGrey = 199,
+		LemonMetallic = 200,
+		LightStoneGrey = 208,
+		DarkCurry = 209,
+		FadedGreen = 210,
+		Turquoise = 211,
+		LightRoyalBlue = 212,
+		MediumRoyalBlue = 213,
+		Rust = 216,
+		Brown = 217,
+		ReddishLilac = 218,
+		Lilac = 219,
+		LightLilac = 220,
+		BrightPurple = 221,
+		LightPurple = 222,
+		LightPink = 223,
+		LightBrickYellow = 224,
+		WarmYellowishOrange = 225,
+		CoolYellow = 226,
+		DoveBlue = 232,
+		MediumLilac = 268,
+		SlimeGreen = 301,
+		SmokyGrey = 302,
+		DarkBlue = 303,
+		ParsleyGreen = 304,
+		SteelBlue = 305,
+		StormBlue = 306,
+		Lapis = 307,
+		DarkIndigo = 308,
+		SeaGreen = 309,
+		Shamrock = 310,
+		Fossil = 311,
+		Mulberry = 312,
+		ForestGreen = 313,
+		CadetBlue = 314,
+		ElectricBlue = 315,
+		Eggplant = 316,
+		Moss = 317,
+		Artichoke = 318,
+		SageGreen = 319,
+		GhostGrey = 320,
+		Lilac2 = 321,
+		Plum = 322,
+		Olivine = 323,
+		LaurelGreen = 324,
+		QuillGrey = 325,
+		Crimson = 327,
+		Mint = 328,
+		BabyBlue = 329,
+		CarnationPink = 330,
+		Persimmon = 331,
+		Maroon = 332,
+		Gold = 333,
+		DaisyOrange = 334,
+		Pearl = 335,
+		Fog = 336,
+		Salmon = 337,
+		Terracotta = 338,
+		Cocoa = 339,
+		Wheat = 340,
+		Buttermilk = 341,
+		Mauve = 342,
+		Sunrise = 343,
+		Tawny = 344,
+		Rust2 = 345,
+		Cashmere = 346,
+		Khaki = 347,
+		LilyWhite = 348,
+		Seashell = 349,
+		Burgundy = 350,
+		Cork = 351,
+		Burlap = 352,
+		Beige = 353,
+		Oyster = 354,
+		PineCone = 355,
+		FawnBrown = 356,
+		HurricaneGrey = 357,
+		CloudyGrey = 358,
+		Linen = 359,
+		Copper = 360,
+		DirtBrown = 361,
+		Bronze = 362,
+		Flint = 363,
+		DarkTaupe = 364,
+		BurntSienna = 365,
+		InstitutionalWhite = 1001,
+		MidGray = 1002,
+		ReallyBlack = 1003,
+		ReallyRed = 1004,
+		DeepOrange = 1005,
+		Alder = 1006,
+		DustyRose = 1007,
+		Olive = 1008,
+		NewYeller = 1009,
+		ReallyBlue = 1010,
+		NavyBlue = 1011,
+		DeepBlue = 1012,
+		Cyan = 1013,
+		CGABrown = 1014,
+		Magenta = 1015,
+		Pink = 1016,
+		DeepOrange2 = 1017,
+		Teal = 1018,
+		Toothpaste = 1019,
+		LimeGreen = 1020,
+		Camo = 1021,
+		Grime = 1022,
+		Lavender = 1023,
+		PastelLightBlue = 1024,
+		PastelOrange = 1025,
+		PastelViolet = 1026,
+		PastelBlueGreen = 1027,
+		PastelGreen = 1028,
+		PastelYellow = 1029,
+		PastelBrown = 1030,
+		RoyalPurple = 1031,
+		HotPink = 1032,
+	}
+}
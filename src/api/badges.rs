@@ -1,76 +1,98 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 
+use crate::api::ids::{BadgeId, PlaceId, UniverseId, UserId};
 use crate::api::routes::RobloxApi;
+use crate::api::timestamp::Timestamp;
 use crate::api::Limit;
 use crate::errors::RoboltError;
+use crate::utils::batch::BatchResult;
 use crate::utils::client::Authenticated;
+use crate::utils::pagination::Paginator;
 use crate::utils::response::DataResponse;
 use crate::Robolt;
 
-impl<State> Robolt<State> {
-	pub async fn badge(&self, badge_id: u64) -> Result<Badge, RoboltError> {
-		self.request(RobloxApi::Badges, format!("/v1/badges/{badge_id}"))
-			.send()
-			.await
-	}
+/// The maximum number of IDs per awarded dates lookup
+const AWARDED_DATES_BATCH_SIZE: usize = 100;
 
-	pub async fn universe_badges(&self, universe_id: u64, limit: Limit) -> Result<Vec<Badge>, RoboltError> {
+impl<State> Robolt<State> {
+	pub async fn badge(&self, badge_id: impl Into<BadgeId>) -> Result<Badge, RoboltError> {
 		self.request(
 			RobloxApi::Badges,
-			format!("/v1/universes/{universe_id}/badges?limit={}", limit as u8),
+			format!("/v1/badges/{badge_id}", badge_id = badge_id.into()),
 		)
-		.send::<DataResponse<Badge>>()
+		.send()
 		.await
-		.map(|res| res.data)
 	}
 
-	pub async fn user_badges(&self, user_id: u64, limit: Limit) -> Result<Vec<Badge>, RoboltError> {
-		self.request(
+	pub fn universe_badges(
+		&self,
+		universe_id: impl Into<UniverseId>,
+		limit: Limit,
+	) -> Paginator<'_, State, Badge> {
+		Paginator::new(
+			self,
 			RobloxApi::Badges,
-			format!("/v1/users/{user_id}/badges?limit={}", limit as u8),
+			format!("/v1/universes/{universe_id}/badges", universe_id = universe_id.into()),
+			limit,
 		)
-		.send::<DataResponse<Badge>>()
-		.await
-		.map(|res| res.data)
 	}
 
-	pub async fn awarded_badge_timestamps(
-		&self,
-		user_id: u64,
-		badge_ids: Vec<u64>,
-	) -> Result<Vec<AwardedBadgeTimestamp>, RoboltError> {
-		let badge_ids = badge_ids
-			.iter()
-			.map(|id| id.to_string())
-			.collect::<Vec<String>>()
-			.join(",");
-
-		self.request(
+	pub fn user_badges(&self, user_id: impl Into<UserId>, limit: Limit) -> Paginator<'_, State, Badge> {
+		Paginator::new(
+			self,
 			RobloxApi::Badges,
-			format!("/v1/users/{user_id}/badges/awarded-dates?badgeIds={badge_ids}"),
+			format!("/v1/users/{user_id}/badges", user_id = user_id.into()),
+			limit,
 		)
-		.send::<DataResponse<AwardedBadgeTimestamp>>()
+	}
+
+	/// Sent in chunks of up to 100 IDs
+	pub async fn awarded_badge_timestamps(
+		&self,
+		user_id: impl Into<UserId>,
+		badge_ids: Vec<impl Into<BadgeId>>,
+	) -> BatchResult<BadgeId, AwardedBadgeTimestamp> {
+		let user_id = user_id.into();
+		let badge_ids = badge_ids.into_iter().map(Into::into).collect();
+
+		self.chunked(badge_ids, AWARDED_DATES_BATCH_SIZE, |badge_ids| async move {
+			let badge_ids = badge_ids
+				.iter()
+				.map(|id| id.to_string())
+				.collect::<Vec<String>>()
+				.join(",");
+
+			self.request(
+				RobloxApi::Badges,
+				format!("/v1/users/{user_id}/badges/awarded-dates?badgeIds={badge_ids}"),
+			)
+			.send::<DataResponse<AwardedBadgeTimestamp>>()
+			.await
+			.map(|res| res.data)
+		})
 		.await
-		.map(|res| res.data)
 	}
 }
 
 impl Robolt<Authenticated> {
-	pub fn update_badge(&self, badge_id: u64) -> BadgeUpdateBuilder {
-		BadgeUpdateBuilder::new(badge_id, self)
+	pub fn update_badge(&self, badge_id: impl Into<BadgeId>) -> BadgeUpdateBuilder<'_> {
+		BadgeUpdateBuilder::new(badge_id.into(), self)
 	}
 
-	pub async fn remove_badge(&self, badge_id: u64) -> Result<(), RoboltError> {
-		self.request(RobloxApi::Badges, format!("/v1/user/badges/{badge_id}"))
-			.method(Method::DELETE)
-			.send()
-			.await
+	pub async fn remove_badge(&self, badge_id: impl Into<BadgeId>) -> Result<(), RoboltError> {
+		self.request(
+			RobloxApi::Badges,
+			format!("/v1/user/badges/{badge_id}", badge_id = badge_id.into()),
+		)
+		.method(Method::DELETE)
+		.send()
+		.await
 	}
 }
 
 impl<'a> BadgeUpdateBuilder<'a> {
-	fn new(id: u64, client: &'a Robolt<Authenticated>) -> Self {
+	fn new(id: BadgeId, client: &'a Robolt<Authenticated>) -> Self {
 		Self {
 			id,
 			client,
@@ -107,7 +129,7 @@ impl<'a> BadgeUpdateBuilder<'a> {
 #[derive(Serialize)]
 pub struct BadgeUpdateBuilder<'a> {
 	#[serde(skip_serializing)]
-	id: u64,
+	id: BadgeId,
 	#[serde(skip_serializing)]
 	client: &'a Robolt<Authenticated>,
 	#[serde(skip_serializing_if = "Option::is_none")]
@@ -118,17 +140,17 @@ pub struct BadgeUpdateBuilder<'a> {
 	enabled: Option<bool>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AwardedBadgeTimestamp {
-	pub badge_id: u64,
-	pub awarded_date: String,
+	pub badge_id: BadgeId,
+	pub awarded_date: Timestamp,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Badge {
-	pub id: u64,
+	pub id: BadgeId,
 	pub name: String,
 	pub description: Option<String>,
 	pub display_name: String,
@@ -136,13 +158,13 @@ pub struct Badge {
 	pub enabled: bool,
 	pub icon_image_id: u64,
 	pub display_icon_image_id: u64,
-	pub created: String,
-	pub updated: String,
+	pub created: Timestamp,
+	pub updated: Timestamp,
 	pub statistics: BadgeStats,
 	pub awarding_universe: Option<AwardingUniverse>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BadgeStats {
 	pub past_day_awarded_count: u64,
@@ -150,10 +172,10 @@ pub struct BadgeStats {
 	pub win_rate_percentage: f32,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AwardingUniverse {
-	pub id: u64,
+	pub id: UniverseId,
 	pub name: String,
-	pub root_place_id: u64,
-}
\ No newline at end of file
+	pub root_place_id: PlaceId,
+}
@@ -1,30 +1,42 @@
 use reqwest::Method;
 use serde::{Deserialize, Serialize};
 use serde_json::json;
-use serde_repr::Deserialize_repr;
 
+use crate::api::ids::{AssetId, BundleId, UserId};
 use crate::api::routes::RobloxApi;
+use crate::api::timestamp::Timestamp;
 use crate::api::Limit;
 use crate::errors::RoboltError;
+use crate::utils::batch::BatchResult;
 use crate::utils::client::Authenticated;
+use crate::utils::pagination::Paginator;
 use crate::utils::response::{DataResponse, EmptyResponse};
 use crate::Robolt;
 
+/// The maximum number of IDs per bundle details lookup
+const BUNDLES_BATCH_SIZE: usize = 100;
+
 impl<State> Robolt<State> {
-	pub async fn asset_parent_bundles(&self, asset_id: u64, limit: Limit) -> Result<Vec<Bundle>, RoboltError> {
-		self.request(
+	pub fn asset_parent_bundles(
+		&self,
+		asset_id: impl Into<AssetId>,
+		limit: Limit,
+	) -> Paginator<'_, State, Bundle> {
+		Paginator::new(
+			self,
 			RobloxApi::Catalog,
-			format!("/v1/assets/{asset_id}/bundles?limit={}", limit as u8),
+			format!("/v1/assets/{asset_id}/bundles", asset_id = asset_id.into()),
+			limit,
 		)
-		.send::<DataResponse<Bundle>>()
-		.await
-		.map(|res| res.data)
 	}
 
-	pub async fn bundle(&self, bundle_id: u64) -> Result<Bundle, RoboltError> {
-		self.request(RobloxApi::Catalog, format!("/v1/bundles/{bundle_id}/details"))
-			.send()
-			.await
+	pub async fn bundle(&self, bundle_id: impl Into<BundleId>) -> Result<Bundle, RoboltError> {
+		self.request(
+			RobloxApi::Catalog,
+			format!("/v1/bundles/{bundle_id}/details", bundle_id = bundle_id.into()),
+		)
+		.send()
+		.await
 	}
 
 	/// Fetches [Bundle] recommendation list based on a bundle associated with
@@ -33,74 +45,104 @@ impl<State> Robolt<State> {
 	/// ### Arguments
 	/// * `bundle_id` - The ID of the bundle to base recommendations on
 	/// * `amount` - The amount of recommendations to fetch (amount <= 50)
-	pub async fn bundle_recommendations(&self, bundle_id: u64, amount: u8) -> Result<Vec<Bundle>, RoboltError> {
+	pub async fn bundle_recommendations(
+		&self,
+		bundle_id: impl Into<BundleId>,
+		amount: u8,
+	) -> Result<Vec<Bundle>, RoboltError> {
 		self.request(
 			RobloxApi::Catalog,
-			format!("/v1/bundles/{bundle_id}/recommendations?numItems={amount}"),
+			format!(
+				"/v1/bundles/{bundle_id}/recommendations?numItems={amount}",
+				bundle_id = bundle_id.into()
+			),
 		)
 		.send::<DataResponse<Bundle>>()
 		.await
 		.map(|res| res.data)
 	}
 
-	pub async fn bundles(&self, bundle_ids: Vec<u64>) -> Result<Vec<Bundle>, RoboltError> {
-		let bundle_ids = bundle_ids
-			.iter()
-			.map(|id| id.to_string())
-			.collect::<Vec<String>>()
-			.join(",");
+	/// Sent in chunks of up to 100 IDs
+	pub async fn bundles(&self, bundle_ids: Vec<impl Into<BundleId>>) -> BatchResult<BundleId, Bundle> {
+		let bundle_ids = bundle_ids.into_iter().map(Into::into).collect();
+
+		self.chunked(bundle_ids, BUNDLES_BATCH_SIZE, |bundle_ids| async move {
+			let bundle_ids = bundle_ids
+				.iter()
+				.map(|id| id.to_string())
+				.collect::<Vec<String>>()
+				.join(",");
+
+			self.request(
+				RobloxApi::Catalog,
+				format!("/v1/bundles/details?bundleIds={bundle_ids}"),
+			)
+			.send()
+			.await
+		})
+		.await
+	}
 
+	pub async fn user_bundles(&self, user_id: impl Into<UserId>) -> Result<Vec<OwnedBundle>, RoboltError> {
 		self.request(
 			RobloxApi::Catalog,
-			format!("/v1/bundles/details?bundleIds={bundle_ids}"),
+			format!("/v1/users/{user_id}/bundles", user_id = user_id.into()),
 		)
-		.send()
+		.send::<DataResponse<OwnedBundle>>()
 		.await
+		.map(|res| res.data)
 	}
 
-	pub async fn user_bundles(&self, user_id: u64) -> Result<Vec<OwnedBundle>, RoboltError> {
-		self.request(RobloxApi::Catalog, format!("/v1/users/{user_id}/bundles"))
-			.send::<DataResponse<OwnedBundle>>()
-			.await
-			.map(|res| res.data)
-	}
-
-	pub async fn user_bundles_filter(
+	pub fn user_bundles_filter(
 		&self,
-		user_id: u64,
+		user_id: impl Into<UserId>,
 		bundle_type: BundleType,
 		limit: Limit,
-	) -> Result<Vec<OwnedBundle>, RoboltError> {
-		self.request(
+	) -> Paginator<'_, State, OwnedBundle> {
+		Paginator::new(
+			self,
 			RobloxApi::Catalog,
 			format!(
-				"/v1/users/{user_id}/bundles/{}?limit={}",
-				bundle_type as u8, limit as u8
+				"/v1/users/{user_id}/bundles/{}",
+				u8::from(bundle_type),
+				user_id = user_id.into()
 			),
+			limit,
 		)
-		.send::<DataResponse<OwnedBundle>>()
-		.await
-		.map(|res| res.data)
 	}
 
-	pub async fn asset_favorite_count(&self, asset_id: u64) -> Result<u64, RoboltError> {
-		self.request(RobloxApi::Catalog, format!("/v1/favorites/assets/{asset_id}/count"))
-			.send()
-			.await
+	pub async fn asset_favorite_count(&self, asset_id: impl Into<AssetId>) -> Result<u64, RoboltError> {
+		self.request(
+			RobloxApi::Catalog,
+			format!("/v1/favorites/assets/{asset_id}/count", asset_id = asset_id.into()),
+		)
+		.send()
+		.await
 	}
 
-	pub async fn bundle_favorite_count(&self, bundle_id: u64) -> Result<u64, RoboltError> {
-		self.request(RobloxApi::Catalog, format!("/v1/favorites/bundles/{bundle_id}/count"))
-			.send()
-			.await
+	pub async fn bundle_favorite_count(&self, bundle_id: impl Into<BundleId>) -> Result<u64, RoboltError> {
+		self.request(
+			RobloxApi::Catalog,
+			format!("/v1/favorites/bundles/{bundle_id}/count", bundle_id = bundle_id.into()),
+		)
+		.send()
+		.await
 	}
 }
 
 impl Robolt<Authenticated> {
-	pub async fn favorite_asset(&self, user_id: u64, asset_id: u64) -> Result<(), RoboltError> {
+	pub async fn favorite_asset(
+		&self,
+		user_id: impl Into<UserId>,
+		asset_id: impl Into<AssetId>,
+	) -> Result<(), RoboltError> {
 		self.request(
 			RobloxApi::Catalog,
-			format!("/v1/favorites/users/{user_id}/assets/{asset_id}/favorite"),
+			format!(
+				"/v1/favorites/users/{user_id}/assets/{asset_id}/favorite",
+				user_id = user_id.into(),
+				asset_id = asset_id.into()
+			),
 		)
 		.method(Method::POST)
 		.send::<EmptyResponse>()
@@ -109,10 +151,18 @@ impl Robolt<Authenticated> {
 		Ok(())
 	}
 
-	pub async fn unfavorite_asset(&self, user_id: u64, asset_id: u64) -> Result<(), RoboltError> {
+	pub async fn unfavorite_asset(
+		&self,
+		user_id: impl Into<UserId>,
+		asset_id: impl Into<AssetId>,
+	) -> Result<(), RoboltError> {
 		self.request(
 			RobloxApi::Catalog,
-			format!("/v1/favorites/users/{user_id}/assets/{asset_id}/favorite"),
+			format!(
+				"/v1/favorites/users/{user_id}/assets/{asset_id}/favorite",
+				user_id = user_id.into(),
+				asset_id = asset_id.into()
+			),
 		)
 		.method(Method::DELETE)
 		.send::<EmptyResponse>()
@@ -121,10 +171,18 @@ impl Robolt<Authenticated> {
 		Ok(())
 	}
 
-	pub async fn favorite_bundle(&self, user_id: u64, bundle_id: u64) -> Result<(), RoboltError> {
+	pub async fn favorite_bundle(
+		&self,
+		user_id: impl Into<UserId>,
+		bundle_id: impl Into<BundleId>,
+	) -> Result<(), RoboltError> {
 		self.request(
 			RobloxApi::Catalog,
-			format!("/v1/favorites/users/{user_id}/bundles/{bundle_id}/favorite"),
+			format!(
+				"/v1/favorites/users/{user_id}/bundles/{bundle_id}/favorite",
+				user_id = user_id.into(),
+				bundle_id = bundle_id.into()
+			),
 		)
 		.method(Method::POST)
 		.send::<EmptyResponse>()
@@ -133,10 +191,18 @@ impl Robolt<Authenticated> {
 		Ok(())
 	}
 
-	pub async fn unfavorite_bundle(&self, user_id: u64, bundle_id: u64) -> Result<(), RoboltError> {
+	pub async fn unfavorite_bundle(
+		&self,
+		user_id: impl Into<UserId>,
+		bundle_id: impl Into<BundleId>,
+	) -> Result<(), RoboltError> {
 		self.request(
 			RobloxApi::Catalog,
-			format!("/v1/favorites/users/{user_id}/bundles/{bundle_id}/favorite"),
+			format!(
+				"/v1/favorites/users/{user_id}/bundles/{bundle_id}/favorite",
+				user_id = user_id.into(),
+				bundle_id = bundle_id.into()
+			),
 		)
 		.method(Method::DELETE)
 		.send::<EmptyResponse>()
@@ -147,12 +213,16 @@ impl Robolt<Authenticated> {
 
 	pub async fn asset_favorite_model(
 		&self,
-		user_id: u64,
-		asset_id: u64,
+		user_id: impl Into<UserId>,
+		asset_id: impl Into<AssetId>,
 	) -> Result<Option<AssetFavorite>, RoboltError> {
 		self.request(
 			RobloxApi::Catalog,
-			format!("/v1/favorites/users/{user_id}/assets/{asset_id}/favorite"),
+			format!(
+				"/v1/favorites/users/{user_id}/assets/{asset_id}/favorite",
+				user_id = user_id.into(),
+				asset_id = asset_id.into()
+			),
 		)
 		.send()
 		.await
@@ -160,12 +230,16 @@ impl Robolt<Authenticated> {
 
 	pub async fn bundle_favorite_model(
 		&self,
-		user_id: u64,
-		bundle_id: u64,
+		user_id: impl Into<UserId>,
+		bundle_id: impl Into<BundleId>,
 	) -> Result<Option<BundleFavorite>, RoboltError> {
 		self.request(
 			RobloxApi::Catalog,
-			format!("/v1/favorites/users/{user_id}/bundles/{bundle_id}/favorite"),
+			format!(
+				"/v1/favorites/users/{user_id}/bundles/{bundle_id}/favorite",
+				user_id = user_id.into(),
+				bundle_id = bundle_id.into()
+			),
 		)
 		.send()
 		.await
@@ -173,7 +247,7 @@ impl Robolt<Authenticated> {
 
 	pub async fn favorite_bundles(
 		&self,
-		user_id: u64,
+		user_id: impl Into<UserId>,
 		subcategory: CatalogSubcategory,
 		limit: Limit,
 		page: u32,
@@ -182,7 +256,9 @@ impl Robolt<Authenticated> {
 			RobloxApi::Catalog,
 			format!(
 				"/v1/favorites/users/{user_id}/favorites/{}/bundles?pageNumber={page}&itemsPerPage={}",
-				subcategory as u8, limit as u8
+				subcategory as u8,
+				limit as u8,
+				user_id = user_id.into(),
 			),
 		)
 		.send()
@@ -207,7 +283,7 @@ pub struct CatalogSearchItem {
 	pub item_type: ItemType,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CatalogSearchResponse {
 	pub id: u64,
@@ -238,92 +314,96 @@ pub struct CatalogSearchResponse {
 	pub sale_location_type: SaleLocationType,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum CreatorType {
 	Group,
 	User,
+	#[serde(untagged)]
+	/// A value unknown to this version of the crate
+	Unknown(String),
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize_repr)]
-#[repr(u8)]
-pub enum AssetType {
-	Image = 1,
-	TShirt = 2,
-	Audio = 3,
-	Mesh = 4,
-	Lua = 5,
-	HTML = 6,
-	Text = 7,
-	Hat = 8,
-	Place = 9,
-	Model = 10,
-	Shirt = 11,
-	Pants = 12,
-	Decal = 13,
-	Avatar = 16,
-	Head = 17,
-	Face = 18,
-	Gear = 19,
-	Badge = 21,
-	GroupEmblem = 22,
-	Animation = 24,
-	Arms = 25,
-	Legs = 26,
-	Torso = 27,
-	RightArm = 28,
-	LeftArm = 29,
-	LeftLeg = 30,
-	RightLeg = 31,
-	Package = 32,
-	YouTubeVideo = 33,
-	GamePass = 34,
-	App = 35,
-	Code = 37,
-	Plugin = 38,
-	SolidModel = 39,
-	MeshPart = 40,
-	HairAccessory = 41,
-	FaceAccessory = 42,
-	NeckAccessory = 43,
-	ShoulderAccessory = 44,
-	FrontAccessory = 45,
-	BackAccessory = 46,
-	WaistAccessory = 47,
-	ClimbAnimation = 48,
-	DeathAnimation = 49,
-	FallAnimation = 50,
-	IdleAnimation = 51,
-	JumpAnimation = 52,
-	RunAnimation = 53,
-	SwimAnimation = 54,
-	WalkAnimation = 55,
-	PoseAnimation = 56,
-	LocalizationTableManifest = 59,
-	LocalizationTableTranslation = 60,
-	EmoteAnimation = 61,
-	Video = 62,
-	TexturePack = 63,
-	TShirtAccessory = 64,
-	ShirtAccessory = 65,
-	PantsAccessory = 66,
-	JacketAccessory = 67,
-	SweaterAccessory = 68,
-	ShortsAccessory = 69,
-	LeftShoeAccessory = 70,
-	RightShoeAccessory = 71,
-	DressSkirtAccessory = 72,
-	FontFamily = 73,
-	FontFace = 74,
-	MeshHiddenSurfaceRemoval = 75,
-	EyebrowAccessory = 76,
-	EyelashAccessory = 77,
-	MoodAnimation = 78,
-	DynamicHead = 79,
-	CodeSnippet = 80,
+int_enum! {
+	#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+	#[serde(from = "u8", into = "u8")]
+	pub enum AssetType: u8 {
+		Image = 1,
+		TShirt = 2,
+		Audio = 3,
+		Mesh = 4,
+		Lua = 5,
+		HTML = 6,
+		Text = 7,
+		Hat = 8,
+		Place = 9,
+		Model = 10,
+		Shirt = 11,
+		Pants = 12,
+		Decal = 13,
+		Avatar = 16,
+		Head = 17,
+		Face = 18,
+		Gear = 19,
+		Badge = 21,
+		GroupEmblem = 22,
+		Animation = 24,
+		Arms = 25,
+		Legs = 26,
+		Torso = 27,
+		RightArm = 28,
+		LeftArm = 29,
+		LeftLeg = 30,
+		RightLeg = 31,
+		Package = 32,
+		YouTubeVideo = 33,
+		GamePass = 34,
+		App = 35,
+		Code = 37,
+		Plugin = 38,
+		SolidModel = 39,
+		MeshPart = 40,
+		HairAccessory = 41,
+		FaceAccessory = 42,
+		NeckAccessory = 43,
+		ShoulderAccessory = 44,
+		FrontAccessory = 45,
+		BackAccessory = 46,
+		WaistAccessory = 47,
+		ClimbAnimation = 48,
+		DeathAnimation = 49,
+		FallAnimation = 50,
+		IdleAnimation = 51,
+		JumpAnimation = 52,
+		RunAnimation = 53,
+		SwimAnimation = 54,
+		WalkAnimation = 55,
+		PoseAnimation = 56,
+		LocalizationTableManifest = 59,
+		LocalizationTableTranslation = 60,
+		EmoteAnimation = 61,
+		Video = 62,
+		TexturePack = 63,
+		TShirtAccessory = 64,
+		ShirtAccessory = 65,
+		PantsAccessory = 66,
+		JacketAccessory = 67,
+		SweaterAccessory = 68,
+		ShortsAccessory = 69,
+		LeftShoeAccessory = 70,
+		RightShoeAccessory = 71,
+		DressSkirtAccessory = 72,
+		FontFamily = 73,
+		FontFace = 74,
+		MeshHiddenSurfaceRemoval = 75,
+		EyebrowAccessory = 76,
+		EyelashAccessory = 77,
+		MoodAnimation = 78,
+		DynamicHead = 79,
+		CodeSnippet = 80,
+	}
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
-#[repr(u8)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum CatalogItemGenre {
 	#[default]
 	All,
@@ -341,10 +421,12 @@ pub enum CatalogItemGenre {
 	Sports,
 	Ninja,
 	WildWest,
+	#[serde(untagged)]
+	/// A value unknown to this version of the crate
+	Unknown(String),
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
-#[repr(u8)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum SaleLocationType {
 	NotApplicable,
 	ShopOnly,
@@ -353,43 +435,48 @@ pub enum SaleLocationType {
 	ExperiencesById,
 	ShopAndAllExperiences,
 	ExperiencesDevApiOnly,
+	#[serde(untagged)]
+	/// A value unknown to this version of the crate
+	Unknown(String),
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize_repr)]
-#[repr(u8)]
-pub enum CatalogItemStatus {
-	New = 1,
-	Sale = 2,
-	SaleTimer = 7,
+int_enum! {
+	#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+	#[serde(from = "u8", into = "u8")]
+	pub enum CatalogItemStatus: u8 {
+		New = 1,
+		Sale = 2,
+		SaleTimer = 7,
+	}
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AssetFavorite {
-	user_id: u64,
-	asset_id: u64,
-	created: String,
+	pub user_id: UserId,
+	pub asset_id: AssetId,
+	pub created: Timestamp,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FavoriteBundles {
-	favorites: Vec<Bundle>,
-	more_favorites: bool,
+	pub favorites: Vec<Bundle>,
+	pub more_favorites: bool,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BundleFavorite {
-	user_id: u64,
-	bundle_id: u64,
-	created: String,
+	pub user_id: UserId,
+	pub bundle_id: BundleId,
+	pub created: Timestamp,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Bundle {
-	pub id: u64,
+	pub id: BundleId,
 	pub name: String,
 	pub description: String,
 	pub bundle_type: String,
@@ -399,16 +486,16 @@ pub struct Bundle {
 	pub item_restrictions: Vec<CatalogItemRestriction>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OwnedBundle {
-	pub id: u64,
+	pub id: BundleId,
 	pub name: String,
 	pub bundle_type: String,
 	pub creator: BundleCreator,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BundleItem {
 	pub owned: Option<bool>,
@@ -418,7 +505,7 @@ pub struct BundleItem {
 	pub item_type: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BundleCreator {
 	pub id: u64,
@@ -428,7 +515,7 @@ pub struct BundleCreator {
 	pub has_verified_badge: bool,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BundleProduct {
 	pub id: u64,
@@ -442,31 +529,36 @@ pub struct BundleProduct {
 	pub premium_pricing: Option<PremiumPricing>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PremiumPricing {
 	pub premium_discount_percentage: u64,
 	pub premium_price_in_robux: u64,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize_repr)]
-#[repr(u8)]
-pub enum BundleType {
-	#[default]
-	BodyParts = 1,
-	AvatarAnimations = 2,
-	Shoes = 3,
-	DynamicHead = 4,
+int_enum! {
+	#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
+	#[serde(from = "u8", into = "u8")]
+	pub enum BundleType: u8 {
+		#[default]
+		BodyParts = 1,
+		AvatarAnimations = 2,
+		Shoes = 3,
+		DynamicHead = 4,
+	}
 }
 
 #[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum ItemType {
 	#[default]
-	Asset = 1,
-	Bundle = 2,
+	Asset,
+	Bundle,
+	#[serde(untagged)]
+	/// A value unknown to this version of the crate
+	Unknown(String),
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum CatalogItemRestriction {
 	ThirteenPlus,
 	LimitedUnique,
@@ -477,6 +569,9 @@ pub enum CatalogItemRestriction {
 	Rthro,
 	Live,
 	Collectible,
+	#[serde(untagged)]
+	/// A value unknown to this version of the crate
+	Unknown(String),
 }
 
 #[derive(Default, Debug, Clone, PartialEq)]
@@ -539,4 +634,4 @@ pub enum CatalogSubcategory {
 	ShoesBundles = 64,
 	DressSkirtAccessories = 65,
 	DynamicHeads = 66,
-}
\ No newline at end of file
+}
@@ -17,4 +17,4 @@ impl Robolt<Authenticated> {
 #[derive(Deserialize)]
 struct UserBalance {
 	robux: u64,
-}
\ No newline at end of file
+}
@@ -1,74 +1,81 @@
 use reqwest::Method;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
+use crate::api::ids::{UniverseId, UserId};
 use crate::api::presence::UserPresence;
 use crate::api::routes::RobloxApi;
+use crate::api::timestamp::Timestamp;
 use crate::api::users::{PartialUser, User};
 use crate::api::Limit;
 use crate::errors::RoboltError;
 use crate::utils::client::Authenticated;
+use crate::utils::pagination::Paginator;
 use crate::utils::response::{CountResponse, DataResponse, EmptyResponse};
 use crate::Robolt;
 
 impl<State> Robolt<State> {
-	pub async fn follower_count(&self, user_id: u64) -> Result<u64, RoboltError> {
-		self.request(RobloxApi::Friends, format!("/v1/users/{user_id}/followers/count"))
-			.send::<CountResponse<u64>>()
-			.await
-			.map(|res| res.count)
-	}
-
-	pub async fn following_count(&self, user_id: u64) -> Result<u64, RoboltError> {
-		self.request(RobloxApi::Friends, format!("/v1/users/{user_id}/followings/count"))
-			.send::<CountResponse<u64>>()
-			.await
-			.map(|res| res.count)
-	}
-
-	pub async fn friend_count(&self, user_id: u64) -> Result<u64, RoboltError> {
-		self.request(RobloxApi::Friends, format!("/v1/users/{user_id}/friends/count"))
-			.send::<CountResponse<u64>>()
-			.await
-			.map(|res| res.count)
+	pub async fn follower_count(&self, user_id: impl Into<UserId>) -> Result<u64, RoboltError> {
+		self.request(
+			RobloxApi::Friends,
+			format!("/v1/users/{user_id}/followers/count", user_id = user_id.into()),
+		)
+		.send::<CountResponse<u64>>()
+		.await
+		.map(|res| res.count)
 	}
 
-	pub async fn friends(&self, user_id: u64) -> Result<Vec<User>, RoboltError> {
-		self.request(RobloxApi::Friends, format!("/v1/users/{user_id}/friends"))
-			.send::<DataResponse<User>>()
-			.await
-			.map(|res| res.data)
+	pub async fn following_count(&self, user_id: impl Into<UserId>) -> Result<u64, RoboltError> {
+		self.request(
+			RobloxApi::Friends,
+			format!("/v1/users/{user_id}/followings/count", user_id = user_id.into()),
+		)
+		.send::<CountResponse<u64>>()
+		.await
+		.map(|res| res.count)
 	}
 
-	pub async fn followers(&self, user_id: u64, limit: Limit) -> Result<Vec<User>, RoboltError> {
+	pub async fn friend_count(&self, user_id: impl Into<UserId>) -> Result<u64, RoboltError> {
 		self.request(
 			RobloxApi::Friends,
-			format!("/v1/users/{user_id}/followers?limit={}", limit as u8),
+			format!("/v1/users/{user_id}/friends/count", user_id = user_id.into()),
 		)
-		.send::<DataResponse<User>>()
+		.send::<CountResponse<u64>>()
 		.await
-		.map(|res| res.data)
+		.map(|res| res.count)
 	}
 
-	pub async fn followings(&self, user_id: u64, limit: Limit) -> Result<Vec<User>, RoboltError> {
+	pub async fn friends(&self, user_id: impl Into<UserId>) -> Result<Vec<User>, RoboltError> {
 		self.request(
 			RobloxApi::Friends,
-			format!("/v1/users/{user_id}/followings?limit={}", limit as u8),
+			format!("/v1/users/{user_id}/friends", user_id = user_id.into()),
 		)
 		.send::<DataResponse<User>>()
 		.await
 		.map(|res| res.data)
 	}
-}
 
-impl Robolt<Authenticated> {
-	pub async fn friend_requests(&self, limit: Limit) -> Result<Vec<FriendRequest>, RoboltError> {
-		self.request(
+	pub fn followers(&self, user_id: impl Into<UserId>, limit: Limit) -> Paginator<'_, State, User> {
+		Paginator::new(
+			self,
 			RobloxApi::Friends,
-			format!("/v1/my/friends/requests?limit={}", limit as u8),
+			format!("/v1/users/{user_id}/followers", user_id = user_id.into()),
+			limit,
 		)
-		.send::<DataResponse<FriendRequest>>()
-		.await
-		.map(|res| res.data)
+	}
+
+	pub fn followings(&self, user_id: impl Into<UserId>, limit: Limit) -> Paginator<'_, State, User> {
+		Paginator::new(
+			self,
+			RobloxApi::Friends,
+			format!("/v1/users/{user_id}/followings", user_id = user_id.into()),
+			limit,
+		)
+	}
+}
+
+impl Robolt<Authenticated> {
+	pub fn friend_requests(&self, limit: Limit) -> Paginator<'_, Authenticated, FriendRequest> {
+		Paginator::new(self, RobloxApi::Friends, "/v1/my/friends/requests", limit)
 	}
 
 	pub async fn friend_request_count(&self) -> Result<u64, RoboltError> {
@@ -85,28 +92,34 @@ impl Robolt<Authenticated> {
 			.map(|res| res.count)
 	}
 
-	pub async fn unfriend(&self, user_id: u64) -> Result<(), RoboltError> {
-		self.request(RobloxApi::Friends, format!("/v1/users/{user_id}/unfriend"))
-			.method(Method::POST)
-			.send::<EmptyResponse>()
-			.await?;
+	pub async fn unfriend(&self, user_id: impl Into<UserId>) -> Result<(), RoboltError> {
+		self.request(
+			RobloxApi::Friends,
+			format!("/v1/users/{user_id}/unfriend", user_id = user_id.into()),
+		)
+		.method(Method::POST)
+		.send::<EmptyResponse>()
+		.await?;
 
 		Ok(())
 	}
 
-	pub async fn unfollow(&self, user_id: u64) -> Result<(), RoboltError> {
-		self.request(RobloxApi::Friends, format!("/v1/users/{user_id}/unfollow"))
-			.method(Method::POST)
-			.send::<EmptyResponse>()
-			.await?;
+	pub async fn unfollow(&self, user_id: impl Into<UserId>) -> Result<(), RoboltError> {
+		self.request(
+			RobloxApi::Friends,
+			format!("/v1/users/{user_id}/unfollow", user_id = user_id.into()),
+		)
+		.method(Method::POST)
+		.send::<EmptyResponse>()
+		.await?;
 
 		Ok(())
 	}
 
-	pub async fn decline_friend_request(&self, user_id: u64) -> Result<(), RoboltError> {
+	pub async fn decline_friend_request(&self, user_id: impl Into<UserId>) -> Result<(), RoboltError> {
 		self.request(
 			RobloxApi::Friends,
-			format!("/v1/users/{user_id}/decline-friend-request"),
+			format!("/v1/users/{user_id}/decline-friend-request", user_id = user_id.into()),
 		)
 		.method(Method::POST)
 		.send::<EmptyResponse>()
@@ -115,11 +128,14 @@ impl Robolt<Authenticated> {
 		Ok(())
 	}
 
-	pub async fn accept_friend_request(&self, user_id: u64) -> Result<(), RoboltError> {
-		self.request(RobloxApi::Friends, format!("/v1/users/{user_id}/accept-friend-request"))
-			.method(Method::POST)
-			.send::<EmptyResponse>()
-			.await?;
+	pub async fn accept_friend_request(&self, user_id: impl Into<UserId>) -> Result<(), RoboltError> {
+		self.request(
+			RobloxApi::Friends,
+			format!("/v1/users/{user_id}/accept-friend-request", user_id = user_id.into()),
+		)
+		.method(Method::POST)
+		.send::<EmptyResponse>()
+		.await?;
 
 		Ok(())
 	}
@@ -143,11 +159,14 @@ impl Robolt<Authenticated> {
 			.map(|res| res.data)
 	}
 
-	pub async fn friendship_statuses(&self, user_ids: Vec<u64>) -> Result<Vec<UserRelationship>, RoboltError> {
+	pub async fn friendship_statuses(
+		&self,
+		user_ids: Vec<impl Into<UserId>>,
+	) -> Result<Vec<UserRelationship>, RoboltError> {
 		let user_id = self.me().await?.id;
 		let user_ids = user_ids
-			.iter()
-			.map(|id| id.to_string())
+			.into_iter()
+			.map(|id| Into::<UserId>::into(id).to_string())
 			.collect::<Vec<String>>()
 			.join(",");
 
@@ -168,21 +187,24 @@ impl UserRelationship {
 	}
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub enum FriendshipStatus {
 	NotFriends,
 	Friends,
 	RequestSent,
 	RequestReceived,
+	#[serde(untagged)]
+	/// A value unknown to this version of the crate
+	Unknown(String),
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct UserRelationship {
-	pub id: u64,
+	pub id: UserId,
 	pub status: FriendshipStatus,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct OnlineFriend {
 	#[serde(rename = "userPresence")]
 	pub presence: UserPresence,
@@ -190,7 +212,7 @@ pub struct OnlineFriend {
 	pub user: PartialUser,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FriendRequest {
 	#[serde(flatten)]
@@ -199,12 +221,12 @@ pub struct FriendRequest {
 	pub mutual_friends_list: Vec<String>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct FriendRequestInfo {
-	pub sent_at: String,
-	pub sender_id: u64,
-	pub source_universe_id: Option<u64>,
+	pub sent_at: Timestamp,
+	pub sender_id: UserId,
+	pub source_universe_id: Option<UniverseId>,
 	pub origin_source_type: String,
 	pub contact_name: Option<String>,
-}
\ No newline at end of file
+}
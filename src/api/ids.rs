@@ -0,0 +1,61 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+macro_rules! ids {
+	($($(#[$meta:meta])* $name:ident;)*) => {
+		$(
+			#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+			#[serde(transparent)]
+			$(#[$meta])*
+			pub struct $name(pub u64);
+
+			impl $name {
+				pub fn get(self) -> u64 {
+					self.0
+				}
+			}
+
+			impl From<u64> for $name {
+				fn from(id: u64) -> Self {
+					Self(id)
+				}
+			}
+
+			impl From<$name> for u64 {
+				fn from(id: $name) -> Self {
+					id.0
+				}
+			}
+
+			impl PartialEq<u64> for $name {
+				fn eq(&self, other: &u64) -> bool {
+					self.0 == *other
+				}
+			}
+
+			impl fmt::Display for $name {
+				fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+					self.0.fmt(f)
+				}
+			}
+		)*
+	};
+}
+
+ids! {
+	/// The ID of a user
+	UserId;
+	/// The ID of an experience, which groups its places
+	UniverseId;
+	/// The ID of a single place of an experience
+	PlaceId;
+	/// The ID of an asset (e.g. an accessory or an emote)
+	AssetId;
+	/// The ID of a bundle
+	BundleId;
+	/// The ID of a badge
+	BadgeId;
+	/// The ID of an outfit
+	OutfitId;
+}
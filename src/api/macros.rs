@@ -0,0 +1,52 @@
+/// Defines an enum sent as an integer, with an `Unknown` variant keeping
+/// values added by Roblox after this version of the crate
+///
+/// The enum converts from and into its integer, so it is expected to be
+/// annotated with `#[serde(from = "..", into = "..")]`. Enums that already
+/// have an `Unknown` variant name the catch-all with `unknown = ..`.
+macro_rules! int_enum {
+	(
+		$(#[$meta:meta])*
+		pub enum $name:ident: $repr:ty {
+			$($(#[$variant_meta:meta])* $variant:ident = $value:literal,)*
+		}
+	) => {
+		int_enum! {
+			$(#[$meta])*
+			pub enum $name: $repr, unknown = Unknown {
+				$($(#[$variant_meta])* $variant = $value,)*
+			}
+		}
+	};
+	(
+		$(#[$meta:meta])*
+		pub enum $name:ident: $repr:ty, unknown = $unknown:ident {
+			$($(#[$variant_meta:meta])* $variant:ident = $value:literal,)*
+		}
+	) => {
+		$(#[$meta])*
+		pub enum $name {
+			$($(#[$variant_meta])* $variant,)*
+			/// A value unknown to this version of the crate
+			$unknown($repr),
+		}
+
+		impl From<$repr> for $name {
+			fn from(value: $repr) -> Self {
+				match value {
+					$($value => Self::$variant,)*
+					value => Self::$unknown(value),
+				}
+			}
+		}
+
+		impl From<$name> for $repr {
+			fn from(value: $name) -> Self {
+				match value {
+					$($name::$variant => $value,)*
+					$name::$unknown(value) => value,
+				}
+			}
+		}
+	};
+}
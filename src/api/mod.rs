@@ -1,11 +1,17 @@
+#[macro_use]
+mod macros;
+
 pub mod account_information;
 pub mod account_settings;
 pub mod avatar;
 pub mod badges;
 pub mod catalog;
 pub mod friends;
+pub mod ids;
+pub mod open_cloud;
 pub mod presence;
 pub mod routes;
+pub mod timestamp;
 pub mod users;
 
 // Nothing to export
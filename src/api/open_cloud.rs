@@ -0,0 +1,124 @@
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use reqwest::header::HeaderValue;
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::api::ids::UniverseId;
+use crate::api::routes::RobloxApi;
+use crate::api::timestamp::Timestamp;
+use crate::errors::RoboltError;
+use crate::utils::client::{ApiKey, Unauthenticated};
+use crate::Robolt;
+
+/// The characters escaped in a path segment, i.e. all but the unreserved ones
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~');
+
+impl Robolt<Unauthenticated> {
+	/// Authenticates the client with an Open Cloud API key, sent in the
+	/// `x-api-key` header without sending any request
+	///
+	/// ### Arguments
+	/// * `api_key` - An API key created on the Creator Dashboard
+	pub fn set_api_key(self, api_key: String) -> Result<Robolt<ApiKey>, RoboltError> {
+		if api_key.is_empty() || HeaderValue::from_str(&api_key).is_err() {
+			return Err(RoboltError::Validation(
+				"The API key contains characters that are not allowed in a header".to_string(),
+			));
+		}
+
+		let mut client = self.into_state::<ApiKey>(None, None);
+		client.api_key = Some(api_key);
+		Ok(client)
+	}
+}
+
+impl Robolt<ApiKey> {
+	pub fn remove_api_key(self) -> Robolt<Unauthenticated> {
+		self.into_state(None, None)
+	}
+
+	/// Publishes a message to the servers of an experience subscribed to the
+	/// topic
+	///
+	/// ### Arguments
+	/// * `universe_id` - The experience to publish to
+	/// * `topic` - The topic the servers subscribed to
+	/// * `message` - The message, up to 1kB
+	pub async fn publish_message(
+		&self,
+		universe_id: impl Into<UniverseId>,
+		topic: &str,
+		message: &str,
+	) -> Result<(), RoboltError> {
+		self.request(
+			RobloxApi::OpenCloud,
+			format!(
+				"/messaging-service/v1/universes/{universe_id}/topics/{topic}",
+				universe_id = universe_id.into(),
+				topic = utf8_percent_encode(topic, PATH_SEGMENT)
+			),
+		)
+		.method(Method::POST)
+		.send_body(json!({ "message": message }))
+		.await
+	}
+
+	/// Reads an entry of a standard data store, decoded into `T` (which can be
+	/// a [serde_json::Value])
+	///
+	/// ### Arguments
+	/// * `universe_id` - The experience owning the data store
+	/// * `datastore` - The name of the data store
+	/// * `key` - The key of the entry
+	pub async fn datastore_entry<T: DeserializeOwned>(
+		&self,
+		universe_id: impl Into<UniverseId>,
+		datastore: &str,
+		key: &str,
+	) -> Result<T, RoboltError> {
+		self.request(RobloxApi::OpenCloud, entry_path(universe_id.into()))
+			.query("datastoreName", datastore)
+			.query("entryKey", key)
+			.send()
+			.await
+	}
+
+	/// Creates or overwrites an entry of a standard data store
+	///
+	/// ### Arguments
+	/// * `universe_id` - The experience owning the data store
+	/// * `datastore` - The name of the data store
+	/// * `key` - The key of the entry
+	/// * `value` - The value to store
+	pub async fn set_datastore_entry<T: Serialize>(
+		&self,
+		universe_id: impl Into<UniverseId>,
+		datastore: &str,
+		key: &str,
+		value: T,
+	) -> Result<DataStoreEntryVersion, RoboltError> {
+		self.request(RobloxApi::OpenCloud, entry_path(universe_id.into()))
+			.method(Method::POST)
+			.query("datastoreName", datastore)
+			.query("entryKey", key)
+			.send_body(value)
+			.await
+	}
+}
+
+fn entry_path(universe_id: UniverseId) -> String {
+	format!("/datastores/v1/universes/{universe_id}/standard-datastores/datastore/entries/entry")
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+/// The version of a data store entry created by a write
+pub struct DataStoreEntryVersion {
+	pub version: String,
+	pub deleted: bool,
+	pub content_length: u64,
+	pub created_time: Timestamp,
+	pub object_created_time: Timestamp,
+}
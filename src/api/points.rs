@@ -1,14 +1,23 @@
 use serde::Deserialize;
 
+use crate::api::ids::{UniverseId, UserId};
 use crate::api::routes::RobloxApi;
 use crate::errors::RoboltError;
 use crate::Robolt;
 
 impl<State> Robolt<State> {
-	pub async fn points(&self, user_id: u64, universe_id: u64) -> Result<u64, RoboltError> {
+	pub async fn points(
+		&self,
+		user_id: impl Into<UserId>,
+		universe_id: impl Into<UniverseId>,
+	) -> Result<u64, RoboltError> {
 		self.request(
 			RobloxApi::Points,
-			format!("/v1/universes/{user_id}/users/{universe_id}/all-time"),
+			format!(
+				"/v1/universes/{universe_id}/users/{user_id}/all-time",
+				user_id = user_id.into(),
+				universe_id = universe_id.into()
+			),
 		)
 		.send::<AllTimeScore>()
 		.await
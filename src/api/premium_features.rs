@@ -1,15 +1,16 @@
+use crate::api::ids::UserId;
 use crate::api::routes::RobloxApi;
 use crate::errors::RoboltError;
 use crate::utils::client::Authenticated;
 use crate::Robolt;
 
 impl Robolt<Authenticated> {
-	pub async fn has_premium(&self, user_id: u64) -> Result<bool, RoboltError> {
+	pub async fn has_premium(&self, user_id: impl Into<UserId>) -> Result<bool, RoboltError> {
 		self.request(
 			RobloxApi::PremiumFeatures,
-			format!("/v1/users/{user_id}/validate-membership"),
+			format!("/v1/users/{user_id}/validate-membership", user_id = user_id.into()),
 		)
 		.send()
 		.await
 	}
-}
\ No newline at end of file
+}
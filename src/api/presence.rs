@@ -1,36 +1,55 @@
 use std::collections::HashMap;
 
 use reqwest::Method;
-use serde::Deserialize;
-use serde_repr::Deserialize_repr;
+use serde::{Deserialize, Serialize};
 
+use crate::api::ids::{PlaceId, UniverseId, UserId};
 use crate::api::routes::RobloxApi;
-use crate::errors::RoboltError;
+use crate::api::timestamp::Timestamp;
+use crate::utils::batch::BatchResult;
 use crate::Robolt;
 
+/// The maximum number of IDs per presence lookup
+const PRESENCE_BATCH_SIZE: usize = 50;
+
 impl<State> Robolt<State> {
-	pub async fn presences(&self, user_ids: Vec<u64>) -> Result<Vec<UserPresence>, RoboltError> {
-		let body = HashMap::from([("userIds", user_ids)]);
+	/// Sent in chunks of up to 50 IDs
+	pub async fn presences(&self, user_ids: Vec<impl Into<UserId>>) -> BatchResult<UserId, UserPresence> {
+		let user_ids = user_ids.into_iter().map(Into::into).collect();
+
+		self.chunked(user_ids, PRESENCE_BATCH_SIZE, |user_ids| async move {
+			let body = HashMap::from([("userIds", user_ids)]);
 
-		self.request(RobloxApi::Presence, "/v1/presence/users")
-			.method(Method::POST)
-			.send_body::<_, UserPresences>(body)
-			.await
-			.map(|res| res.user_presences)
+			self.request(RobloxApi::Presence, "/v1/presence/users")
+				.method(Method::POST)
+				.send_body::<_, UserPresences>(body)
+				.await
+				.map(|res| res.user_presences)
+		})
+		.await
 	}
 
-	pub async fn partial_presences(&self, user_ids: Vec<u64>) -> Result<Vec<PartialUserPresence>, RoboltError> {
-		let body = HashMap::from([("userIds", user_ids)]);
+	/// Sent in chunks of up to 50 IDs
+	pub async fn partial_presences(
+		&self,
+		user_ids: Vec<impl Into<UserId>>,
+	) -> BatchResult<UserId, PartialUserPresence> {
+		let user_ids = user_ids.into_iter().map(Into::into).collect();
+
+		self.chunked(user_ids, PRESENCE_BATCH_SIZE, |user_ids| async move {
+			let body = HashMap::from([("userIds", user_ids)]);
 
-		self.request(RobloxApi::Presence, "/v1/presence/last-online")
-			.method(Method::POST)
-			.send_body::<_, LastOnlineTimestamps>(body)
-			.await
-			.map(|res| res.last_online_timestamps)
+			self.request(RobloxApi::Presence, "/v1/presence/last-online")
+				.method(Method::POST)
+				.send_body::<_, LastOnlineTimestamps>(body)
+				.await
+				.map(|res| res.last_online_timestamps)
+		})
+		.await
 	}
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct UserPresence {
 	#[serde(alias = "UserPresenceType")]
@@ -38,36 +57,40 @@ pub struct UserPresence {
 	#[serde(rename = "UserLocationType")]
 	pub user_location_type: Option<UserLocationType>,
 	#[serde(default)]
-	pub user_id: u64,
-	pub last_online: String,
+	pub user_id: UserId,
+	pub last_online: Timestamp,
 	pub last_location: String,
 	pub game_id: Option<u64>,
-	pub place_id: Option<u64>,
-	pub universe_id: Option<u64>,
-	pub root_place_id: Option<u64>,
+	pub place_id: Option<PlaceId>,
+	pub universe_id: Option<UniverseId>,
+	pub root_place_id: Option<PlaceId>,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PartialUserPresence {
-	pub user_id: u64,
-	pub last_online: String,
+	pub user_id: UserId,
+	pub last_online: Timestamp,
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize_repr)]
-#[repr(u8)]
-pub enum UserPresenceType {
-	Offline = 0,
-	Online = 1,
-	InGame = 2,
-	InStudio = 3,
+int_enum! {
+	#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+	#[serde(from = "u8", into = "u8")]
+	pub enum UserPresenceType: u8 {
+		Offline = 0,
+		Online = 1,
+		InGame = 2,
+		InStudio = 3,
+	}
 }
 
-#[derive(Debug, Clone, PartialEq, Deserialize_repr)]
-#[repr(u8)]
-pub enum UserLocationType {
-	Page,
-	Game,
+int_enum! {
+	#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+	#[serde(from = "u8", into = "u8")]
+	pub enum UserLocationType: u8 {
+		Page = 0,
+		Game = 1,
+	}
 }
 
 #[derive(Deserialize)]
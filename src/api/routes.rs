@@ -1,5 +1,6 @@
-use std::ops::Deref;
+use std::collections::HashMap;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum RobloxApi {
 	Presence,
 	Users,
@@ -13,6 +14,13 @@ pub enum RobloxApi {
 	PremiumFeatures,
 	Auth,
 	Catalog,
+	/// Open Cloud, authenticated with an API key
+	OpenCloud,
+	/// The OAuth 2.0 authorization server
+	OAuth,
+	/// The challenge and reauthentication services
+	Challenge,
+	TwoStepVerification,
 	Custom(&'static str),
 }
 
@@ -31,7 +39,44 @@ impl RobloxApi {
 			RobloxApi::PremiumFeatures => "premiumfeatures.roblox.com",
 			RobloxApi::Auth => "auth.roblox.com",
 			RobloxApi::Catalog => "catalog.roblox.com",
-			RobloxApi::Custom(s) => s.deref(),
+			RobloxApi::OpenCloud | RobloxApi::OAuth | RobloxApi::Challenge => "apis.roblox.com",
+			RobloxApi::TwoStepVerification => "twostepverification.roblox.com",
+			RobloxApi::Custom(s) => s,
 		}
 	}
 }
+
+#[derive(Debug, Clone, Default)]
+/// Scheme and host overrides used when building request URLs
+///
+/// Overrides are resolved in the following order:
+///
+/// * The override registered for the specific [RobloxApi] variant
+/// * The global override
+/// * `https://` followed by the variant's Roblox host
+pub(crate) struct BaseUrls {
+	global: Option<String>,
+	overrides: HashMap<RobloxApi, String>,
+}
+
+impl BaseUrls {
+	pub(crate) fn set(&mut self, domain: RobloxApi, base_url: String) {
+		self.overrides.insert(domain, normalize(base_url));
+	}
+
+	pub(crate) fn set_global(&mut self, base_url: String) {
+		self.global = Some(normalize(base_url));
+	}
+
+	pub(crate) fn resolve(&self, domain: &RobloxApi) -> String {
+		self.overrides
+			.get(domain)
+			.or(self.global.as_ref())
+			.cloned()
+			.unwrap_or_else(|| format!("https://{}", domain.url()))
+	}
+}
+
+fn normalize(base_url: String) -> String {
+	base_url.trim_end_matches('/').to_string()
+}
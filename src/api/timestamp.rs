@@ -0,0 +1,76 @@
+use std::fmt;
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(from = "String", into = "String")]
+/// A date and time returned by Roblox
+///
+/// The string is always kept as returned. With the `chrono` feature, it is
+/// also parsed as UTC, tolerating any number of fractional digits and a
+/// missing offset.
+pub struct Timestamp {
+	raw: String,
+	#[cfg(feature = "chrono")]
+	parsed: Option<DateTime<Utc>>,
+}
+
+impl Timestamp {
+	/// The timestamp as returned by Roblox
+	pub fn as_str(&self) -> &str {
+		&self.raw
+	}
+
+	#[cfg(feature = "chrono")]
+	#[doc(cfg(feature = "chrono"))]
+	/// The timestamp in UTC, or `None` if it could not be parsed
+	pub fn date_time(&self) -> Option<DateTime<Utc>> {
+		self.parsed
+	}
+}
+
+#[cfg(feature = "chrono")]
+fn parse(raw: &str) -> Option<DateTime<Utc>> {
+	DateTime::parse_from_rfc3339(raw)
+		.map(|date_time| date_time.with_timezone(&Utc))
+		.or_else(|_| {
+			NaiveDateTime::parse_from_str(raw, "%Y-%m-%dT%H:%M:%S%.f").map(|date_time| date_time.and_utc())
+		})
+		.ok()
+}
+
+impl From<String> for Timestamp {
+	fn from(raw: String) -> Self {
+		Self {
+			#[cfg(feature = "chrono")]
+			parsed: parse(&raw),
+			raw,
+		}
+	}
+}
+
+impl From<Timestamp> for String {
+	fn from(timestamp: Timestamp) -> Self {
+		timestamp.raw
+	}
+}
+
+impl PartialEq<str> for Timestamp {
+	fn eq(&self, other: &str) -> bool {
+		self.raw == other
+	}
+}
+
+impl PartialEq<&str> for Timestamp {
+	fn eq(&self, other: &&str) -> bool {
+		self.raw == *other
+	}
+}
+
+impl fmt::Display for Timestamp {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(&self.raw)
+	}
+}
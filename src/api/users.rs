@@ -1,61 +1,75 @@
-use std::collections::HashMap;
-
 use reqwest::Method;
-use serde::Deserialize;
-use serde_json::Value;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
 
+use crate::api::ids::UserId;
 use crate::api::routes::RobloxApi;
+use crate::api::timestamp::Timestamp;
 use crate::api::Limit;
+use crate::utils::batch::BatchResult;
 use crate::utils::client::Authenticated;
 use crate::utils::errors::RoboltError;
+use crate::utils::pagination::Paginator;
 use crate::utils::response::{DataResponse, EmptyResponse};
 use crate::Robolt;
 
-impl<State> Robolt<State> {
-	pub async fn user(&self, user_id: u64) -> Result<User, RoboltError> {
-		self.request(RobloxApi::Users, format!("/v1/users/{user_id}"))
-			.send()
-			.await
-	}
+/// The maximum number of IDs or usernames per bulk user lookup
+const USERS_BATCH_SIZE: usize = 100;
 
-	pub async fn partial_user(&self, user_id: u64) -> Result<PartialUser, RoboltError> {
-		self.request(RobloxApi::Users, format!("/v1/users/{user_id}"))
-			.send()
-			.await
+impl<State> Robolt<State> {
+	pub async fn user(&self, user_id: impl Into<UserId>) -> Result<User, RoboltError> {
+		self.request(
+			RobloxApi::Users,
+			format!("/v1/users/{user_id}", user_id = user_id.into()),
+		)
+		.send()
+		.await
 	}
 
-	pub async fn search_users(&self, keyword: &str, limit: Limit) -> Result<Vec<PartialUser>, RoboltError> {
+	pub async fn partial_user(&self, user_id: impl Into<UserId>) -> Result<PartialUser, RoboltError> {
 		self.request(
 			RobloxApi::Users,
-			format!("/v1/users/search?keyword={keyword}&limit={}", limit as u8),
+			format!("/v1/users/{user_id}", user_id = user_id.into()),
 		)
-		.send::<DataResponse<PartialUser>>()
+		.send()
 		.await
-		.map(|res| res.data)
 	}
 
+	pub fn search_users(&self, keyword: &str, limit: Limit) -> Paginator<'_, State, PartialUser> {
+		Paginator::new(self, RobloxApi::Users, "/v1/users/search", limit).query("keyword", keyword)
+	}
+
+	/// Sent in chunks of up to 100 IDs
 	pub async fn users_from_ids(
 		&self,
-		user_ids: Vec<u64>,
+		user_ids: Vec<impl Into<UserId>>,
 		exclude_banned: bool,
-	) -> Result<Vec<PartialUser>, RoboltError> {
-		let body = HashMap::from([
-			("excludeBannedUsers", Value::from(exclude_banned)),
-			("userIds", Value::from(user_ids)),
-		]);
-
-		self.request(RobloxApi::Users, "/v1/users")
-			.method(Method::POST)
-			.send_body::<_, DataResponse<PartialUser>>(body)
-			.await
-			.map(|res| res.data)
+	) -> BatchResult<UserId, PartialUser> {
+		let user_ids = user_ids.into_iter().map(Into::into).collect();
+
+		self.chunked(user_ids, USERS_BATCH_SIZE, |user_ids| async move {
+			let body = json!({
+				"excludeBannedUsers": exclude_banned,
+				"userIds": user_ids,
+			});
+
+			self.request(RobloxApi::Users, "/v1/users")
+				.method(Method::POST)
+				.send_body::<_, DataResponse<PartialUser>>(body)
+				.await
+				.map(|res| res.data)
+		})
+		.await
 	}
 
-	pub async fn username_history(&self, user_id: u64) -> Result<Vec<String>, RoboltError> {
-		self.request(RobloxApi::Users, format!("/v1/users/{user_id}/username-history"))
-			.send::<DataResponse<String>>()
-			.await
-			.map(|res| res.data)
+	pub async fn username_history(&self, user_id: impl Into<UserId>) -> Result<Vec<String>, RoboltError> {
+		self.request(
+			RobloxApi::Users,
+			format!("/v1/users/{user_id}/username-history", user_id = user_id.into()),
+		)
+		.send::<DataResponse<String>>()
+		.await
+		.map(|res| res.data)
 	}
 
 	pub async fn validate_display_name(&self, display_name: &str, date_of_birth: &str) -> Result<(), RoboltError> {
@@ -75,44 +89,50 @@ impl Robolt<Authenticated> {
 		self.request(RobloxApi::Users, "/v1/users/authenticated").send().await
 	}
 
+	/// Sent in chunks of up to 100 usernames
 	pub async fn users_from_usernames(
 		&self,
 		usernames: Vec<&str>,
 		exclude_banned: bool,
-	) -> Result<Vec<PartialUser>, RoboltError> {
-		let body = HashMap::from([
-			("excludeBannedUsers", Value::from(exclude_banned)),
-			("usernames", Value::from(usernames)),
-		]);
-
-		self.request(RobloxApi::Users, "/v1/usernames/users")
-			.method(Method::POST)
-			.send_body::<_, DataResponse<PartialUser>>(body)
-			.await
-			.map(|res| res.data)
+	) -> BatchResult<String, PartialUser> {
+		let usernames = usernames.into_iter().map(str::to_string).collect();
+
+		self.chunked(usernames, USERS_BATCH_SIZE, |usernames| async move {
+			let body = json!({
+				"excludeBannedUsers": exclude_banned,
+				"usernames": usernames,
+			});
+
+			self.request(RobloxApi::Users, "/v1/usernames/users")
+				.method(Method::POST)
+				.send_body::<_, DataResponse<PartialUser>>(body)
+				.await
+				.map(|res| res.data)
+		})
+		.await
 	}
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct User {
 	#[serde(rename = "name")]
 	pub username: String,
 	pub external_app_display_name: Option<String>,
 	pub description: Option<String>,
-	pub created: String,
+	pub created: Timestamp,
 	pub is_banned: bool,
 	pub has_verified_badge: bool,
-	pub id: u64,
+	pub id: UserId,
 	pub display_name: String,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[derive(Default, Debug, Clone, PartialEq, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PartialUser {
 	#[serde(alias = "userId")]
-	pub id: u64,
+	pub id: UserId,
 	#[serde(rename = "name")]
 	pub username: String,
 	pub display_name: String,
-}
\ No newline at end of file
+}
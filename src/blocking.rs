@@ -0,0 +1,590 @@
+//! A synchronous client mirroring the asynchronous [Robolt](crate::Robolt)
+//!
+//! Every method blocks the current thread on a runtime owned by the client,
+//! so none of them may be called from within an asynchronous context.
+
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use reqwest::Method;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tokio::runtime::{Builder, Runtime};
+
+use crate::api::account_information::{
+	AuthenticatedUserSocials,
+	Birthdate,
+	Gender,
+	PhoneNumber,
+	RobloxBadge,
+	UserSocials,
+};
+use crate::api::account_settings::{BlockedUsers, Email, PrivacySetting, PrivacyState, TradeValue};
+use crate::api::avatar::{
+	Avatar,
+	AvatarItemFilter,
+	AvatarMetadata,
+	BodyColors,
+	BodyScale,
+	BodyType,
+	DetailedOutfit,
+	FilteredOutfitResponse,
+	GameStartAvatarInfo,
+	InvalidAssets,
+	OutfitFilterBuilder as AsyncOutfitFilterBuilder,
+	OutfitV1,
+	OutfitV2,
+	RecentAvatarItem,
+};
+use crate::api::badges::{AwardedBadgeTimestamp, Badge, BadgeUpdateBuilder as AsyncBadgeUpdateBuilder};
+use crate::api::catalog::{
+	AssetFavorite,
+	Bundle,
+	BundleFavorite,
+	BundleType,
+	CatalogSearchItem,
+	CatalogSearchResponse,
+	CatalogSubcategory,
+	FavoriteBundles,
+	OwnedBundle,
+};
+use crate::api::friends::{FriendRequest, OnlineFriend, UserRelationship};
+use crate::api::ids::{AssetId, BadgeId, BundleId, OutfitId, UniverseId, UserId};
+use crate::api::open_cloud::DataStoreEntryVersion;
+use crate::api::presence::{PartialUserPresence, UserPresence};
+use crate::api::routes::RobloxApi;
+use crate::api::users::{PartialUser, User};
+use crate::api::Limit;
+use crate::batch::BatchResult;
+use crate::challenge::{Challenge, ChallengeSolution, TwoStepVerificationMedia};
+use crate::errors::RoboltError;
+#[cfg(feature = "oauth")]
+use crate::oauth::{Authorization, OAuthConfig, OAuthTokens, UserInfo};
+use crate::pagination::{Page, Paginator as AsyncPaginator, SortOrder};
+use crate::session::Session;
+#[cfg(feature = "oauth")]
+use crate::utils::client::OAuth;
+use crate::utils::client::{ApiKey, Authenticated, RequestBuilder as AsyncRequestBuilder, Unauthenticated};
+
+macro_rules! blocking_methods {
+	($($(#[$meta:meta])* fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $ret:ty;)*) => {
+		$(
+			$(#[$meta])*
+			pub fn $name(&self $(, $arg: $ty)*) -> $ret {
+				self.runtime.block_on(async { self.inner.$name($($arg),*).await })
+			}
+		)*
+	};
+}
+
+macro_rules! blocking_paginators {
+	($($(#[$meta:meta])* fn $name:ident(&self $(, $arg:ident: $ty:ty)*) -> $state:ty, $item:ty;)*) => {
+		$(
+			$(#[$meta])*
+			pub fn $name(&self $(, $arg: $ty)*) -> Paginator<'_, $state, $item> {
+				Paginator::new(self.inner.$name($($arg),*), &self.runtime)
+			}
+		)*
+	};
+}
+
+#[derive(Debug, Clone)]
+/// The synchronous counterpart of [Robolt](crate::Robolt)
+pub struct Robolt<State = Unauthenticated> {
+	inner: crate::Robolt<State>,
+	runtime: Arc<Runtime>,
+}
+
+impl Default for Robolt {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl Robolt {
+	pub fn new() -> Self {
+		Self::from_async(crate::Robolt::new())
+	}
+
+	pub fn from(roblox_cookie: String) -> Result<Robolt<Authenticated>, RoboltError> {
+		Self::new().set_cookie(roblox_cookie)
+	}
+
+	/// See [Robolt::login](crate::Robolt::login)
+	pub fn login(self, roblox_cookie: String) -> Result<(Robolt<Authenticated>, PartialUser), RoboltError> {
+		let (inner, user) = self.runtime.block_on(self.inner.login(roblox_cookie))?;
+
+		Ok((
+			Robolt {
+				inner,
+				runtime: self.runtime,
+			},
+			user,
+		))
+	}
+
+	pub fn set_cookie(self, roblox_cookie: String) -> Result<Robolt<Authenticated>, RoboltError> {
+		let inner = self.runtime.block_on(self.inner.set_cookie(roblox_cookie))?;
+
+		Ok(Robolt {
+			inner,
+			runtime: self.runtime,
+		})
+	}
+
+	/// See [Robolt::restore](crate::Robolt::restore)
+	pub fn restore(self, session: Session) -> Result<Robolt<Authenticated>, RoboltError> {
+		Ok(Robolt {
+			inner: self.inner.restore(session)?,
+			runtime: self.runtime,
+		})
+	}
+
+	/// See [Robolt::set_api_key](crate::Robolt::set_api_key)
+	pub fn set_api_key(self, api_key: String) -> Result<Robolt<ApiKey>, RoboltError> {
+		Ok(Robolt {
+			inner: self.inner.set_api_key(api_key)?,
+			runtime: self.runtime,
+		})
+	}
+}
+
+impl<State> Robolt<State> {
+	blocking_methods! {
+		fn user(&self, user_id: impl Into<UserId>) -> Result<User, RoboltError>;
+		fn partial_user(&self, user_id: impl Into<UserId>) -> Result<PartialUser, RoboltError>;
+		fn users_from_ids(&self, user_ids: Vec<impl Into<UserId>>, exclude_banned: bool) -> BatchResult<UserId, PartialUser>;
+		fn username_history(&self, user_id: impl Into<UserId>) -> Result<Vec<String>, RoboltError>;
+		fn validate_display_name(&self, display_name: &str, date_of_birth: &str) -> Result<(), RoboltError>;
+		fn follower_count(&self, user_id: impl Into<UserId>) -> Result<u64, RoboltError>;
+		fn following_count(&self, user_id: impl Into<UserId>) -> Result<u64, RoboltError>;
+		fn friend_count(&self, user_id: impl Into<UserId>) -> Result<u64, RoboltError>;
+		fn friends(&self, user_id: impl Into<UserId>) -> Result<Vec<User>, RoboltError>;
+		fn badge(&self, badge_id: impl Into<BadgeId>) -> Result<Badge, RoboltError>;
+		fn awarded_badge_timestamps(&self, user_id: impl Into<UserId>, badge_ids: Vec<impl Into<BadgeId>>) -> BatchResult<BadgeId, AwardedBadgeTimestamp>;
+		fn bundle(&self, bundle_id: impl Into<BundleId>) -> Result<Bundle, RoboltError>;
+		fn bundle_recommendations(&self, bundle_id: impl Into<BundleId>, amount: u8) -> Result<Vec<Bundle>, RoboltError>;
+		fn bundles(&self, bundle_ids: Vec<impl Into<BundleId>>) -> BatchResult<BundleId, Bundle>;
+		fn user_bundles(&self, user_id: impl Into<UserId>) -> Result<Vec<OwnedBundle>, RoboltError>;
+		fn asset_favorite_count(&self, asset_id: impl Into<AssetId>) -> Result<u64, RoboltError>;
+		fn bundle_favorite_count(&self, bundle_id: impl Into<BundleId>) -> Result<u64, RoboltError>;
+		fn avatar_metadata(&self) -> Result<AvatarMetadata, RoboltError>;
+		fn avatar(&self, user_id: impl Into<UserId>) -> Result<Avatar, RoboltError>;
+		fn currently_wearing(&self, user_id: impl Into<UserId>) -> Result<Vec<AssetId>, RoboltError>;
+		fn game_start_info(&self, universe_id: impl Into<UniverseId>) -> Result<GameStartAvatarInfo, RoboltError>;
+		fn outfit(&self, outfit_id: impl Into<OutfitId>) -> Result<DetailedOutfit, RoboltError>;
+		fn presences(&self, user_ids: Vec<impl Into<UserId>>) -> BatchResult<UserId, UserPresence>;
+		fn partial_presences(&self, user_ids: Vec<impl Into<UserId>>) -> BatchResult<UserId, PartialUserPresence>;
+		fn roblox_badges(&self, user_id: impl Into<UserId>) -> Result<Vec<RobloxBadge>, RoboltError>;
+		fn user_socials(&self, user_id: impl Into<UserId>) -> Result<UserSocials, RoboltError>;
+		fn points(&self, user_id: impl Into<UserId>, universe_id: impl Into<UniverseId>) -> Result<u64, RoboltError>;
+	}
+
+	blocking_paginators! {
+		fn search_users(&self, keyword: &str, limit: Limit) -> State, PartialUser;
+		fn followers(&self, user_id: impl Into<UserId>, limit: Limit) -> State, User;
+		fn followings(&self, user_id: impl Into<UserId>, limit: Limit) -> State, User;
+		fn universe_badges(&self, universe_id: impl Into<UniverseId>, limit: Limit) -> State, Badge;
+		fn user_badges(&self, user_id: impl Into<UserId>, limit: Limit) -> State, Badge;
+		fn asset_parent_bundles(&self, asset_id: impl Into<AssetId>, limit: Limit) -> State, Bundle;
+		fn user_bundles_filter(&self, user_id: impl Into<UserId>, bundle_type: BundleType, limit: Limit) -> State, OwnedBundle;
+	}
+
+	/// Wraps an asynchronous client, keeping its configuration (e.g. one
+	/// built through [RoboltBuilder](crate::RoboltBuilder))
+	pub fn from_async(inner: crate::Robolt<State>) -> Self {
+		let runtime = Builder::new_current_thread()
+			.enable_all()
+			.build()
+			.expect("Failed to build runtime");
+
+		Self {
+			inner,
+			runtime: Arc::new(runtime),
+		}
+	}
+
+	pub fn is_authenticated(&self) -> bool {
+		self.inner.is_authenticated()
+	}
+
+	/// See [Robolt::clear_cache](crate::Robolt::clear_cache)
+	pub fn clear_cache(&self) {
+		self.inner.clear_cache()
+	}
+
+	/// See [Robolt::invalidate_cache](crate::Robolt::invalidate_cache)
+	pub fn invalidate_cache<S: AsRef<str>>(&self, domain: RobloxApi, path: S) {
+		self.inner.invalidate_cache(domain, path)
+	}
+
+	/// See [Robolt::invalidate_domain_cache](crate::Robolt::invalidate_domain_cache)
+	pub fn invalidate_domain_cache(&self, domain: RobloxApi) {
+		self.inner.invalidate_domain_cache(domain)
+	}
+
+	/// See [Robolt::request](crate::Robolt::request)
+	pub fn request<S: ToString + Display>(&self, domain: RobloxApi, path: S) -> RequestBuilder<'_, State> {
+		RequestBuilder {
+			inner: self.inner.request(domain, path),
+			runtime: &self.runtime,
+		}
+	}
+
+	/// See [Robolt::resubmit](crate::Robolt::resubmit)
+	pub fn resubmit<T: DeserializeOwned>(
+		&self,
+		challenge: &Challenge,
+		solution: &ChallengeSolution,
+	) -> Result<T, RoboltError> {
+		self.runtime.block_on(self.inner.resubmit(challenge, solution))
+	}
+
+	pub fn outfits(&self, user_id: impl Into<UserId>) -> OutfitFilterBuilder<'_, State> {
+		OutfitFilterBuilder {
+			inner: self.inner.outfits(user_id),
+			runtime: &self.runtime,
+		}
+	}
+}
+
+impl Robolt<Authenticated> {
+	blocking_methods! {
+		fn me(&self) -> Result<PartialUser, RoboltError>;
+		fn solve_two_step_verification(&self, challenge: &Challenge, media: TwoStepVerificationMedia, code: &str) -> Result<ChallengeSolution, RoboltError>;
+		fn solve_reauthentication(&self, challenge: &Challenge, password: &str) -> Result<ChallengeSolution, RoboltError>;
+		fn users_from_usernames(&self, usernames: Vec<&str>, exclude_banned: bool) -> BatchResult<String, PartialUser>;
+		fn friend_request_count(&self) -> Result<u64, RoboltError>;
+		fn friend_count_auth(&self) -> Result<u64, RoboltError>;
+		fn unfriend(&self, user_id: impl Into<UserId>) -> Result<(), RoboltError>;
+		fn unfollow(&self, user_id: impl Into<UserId>) -> Result<(), RoboltError>;
+		fn decline_friend_request(&self, user_id: impl Into<UserId>) -> Result<(), RoboltError>;
+		fn accept_friend_request(&self, user_id: impl Into<UserId>) -> Result<(), RoboltError>;
+		fn decline_all_friend_requests(&self) -> Result<(), RoboltError>;
+		fn online_friends(&self) -> Result<Vec<OnlineFriend>, RoboltError>;
+		fn friendship_statuses(&self, user_ids: Vec<impl Into<UserId>>) -> Result<Vec<UserRelationship>, RoboltError>;
+		fn remove_badge(&self, badge_id: impl Into<BadgeId>) -> Result<(), RoboltError>;
+		fn favorite_asset(&self, user_id: impl Into<UserId>, asset_id: impl Into<AssetId>) -> Result<(), RoboltError>;
+		fn unfavorite_asset(&self, user_id: impl Into<UserId>, asset_id: impl Into<AssetId>) -> Result<(), RoboltError>;
+		fn favorite_bundle(&self, user_id: impl Into<UserId>, bundle_id: impl Into<BundleId>) -> Result<(), RoboltError>;
+		fn unfavorite_bundle(&self, user_id: impl Into<UserId>, bundle_id: impl Into<BundleId>) -> Result<(), RoboltError>;
+		fn asset_favorite_model(&self, user_id: impl Into<UserId>, asset_id: impl Into<AssetId>) -> Result<Option<AssetFavorite>, RoboltError>;
+		fn bundle_favorite_model(&self, user_id: impl Into<UserId>, bundle_id: impl Into<BundleId>) -> Result<Option<BundleFavorite>, RoboltError>;
+		fn favorite_bundles(&self, user_id: impl Into<UserId>, subcategory: CatalogSubcategory, limit: Limit, page: u32) -> Result<FavoriteBundles, RoboltError>;
+		fn item(&self, items: Vec<CatalogSearchItem>) -> Result<Vec<CatalogSearchResponse>, RoboltError>;
+		fn avatar_auth(&self) -> Result<Avatar, RoboltError>;
+		fn add_wearing_asset(&self, asset_id: impl Into<AssetId>) -> Result<(), RoboltError>;
+		fn set_wearing_assets(&self, asset_ids: Vec<impl Into<AssetId>>) -> Result<InvalidAssets, RoboltError>;
+		fn remove_wearing_asset(&self, asset_id: impl Into<AssetId>) -> Result<(), RoboltError>;
+		fn redraw_avatar_thumbnail(&self) -> Result<(), RoboltError>;
+		fn set_avatar_type(&self, avatar_type: BodyType) -> Result<(), RoboltError>;
+		fn set_body_colors(&self, body_colors: BodyColors) -> Result<(), RoboltError>;
+		fn set_scales(&self, scales: BodyScale) -> Result<(), RoboltError>;
+		fn delete_outfit(&self, outfit_id: impl Into<OutfitId>) -> Result<(), RoboltError>;
+		fn wear_outfit(&self, outfit_id: impl Into<OutfitId>) -> Result<InvalidAssets, RoboltError>;
+		fn create_outfit(&self, new_outfit: OutfitV1) -> Result<(), RoboltError>;
+		fn update_outfit(&self, outfit_id: impl Into<OutfitId>, updated_outfit: OutfitV2) -> Result<(), RoboltError>;
+		fn recent_avatar_items(&self, item_type: AvatarItemFilter) -> Result<Vec<RecentAvatarItem>, RoboltError>;
+		fn socials_auth(&self) -> Result<AuthenticatedUserSocials, RoboltError>;
+		fn birthdate(&self) -> Result<Birthdate, RoboltError>;
+		fn blurb(&self) -> Result<String, RoboltError>;
+		fn gender(&self) -> Result<Gender, RoboltError>;
+		fn consecutive_xbox_logins(&self) -> Result<u16, RoboltError>;
+		fn phone_number(&self) -> Result<PhoneNumber, RoboltError>;
+		fn privacy(&self, setting: PrivacySetting) -> Result<PrivacyState, RoboltError>;
+		fn blocked_users(&self) -> Result<BlockedUsers, RoboltError>;
+		fn email(&self) -> Result<Email, RoboltError>;
+		fn trade_value(&self) -> Result<TradeValue, RoboltError>;
+		fn balance(&self) -> Result<u64, RoboltError>;
+		fn has_premium(&self, user_id: impl Into<UserId>) -> Result<bool, RoboltError>;
+	}
+
+	blocking_paginators! {
+		fn friend_requests(&self, limit: Limit) -> Authenticated, FriendRequest;
+	}
+
+	/// See [Robolt::session](crate::Robolt::session)
+	pub fn session(&self) -> Result<Session, RoboltError> {
+		self.inner.session()
+	}
+
+	pub fn remove_cookie(self) -> Robolt<Unauthenticated> {
+		Robolt {
+			inner: self.inner.remove_cookie(),
+			runtime: self.runtime,
+		}
+	}
+
+	pub fn update_badge(&self, badge_id: impl Into<BadgeId>) -> BadgeUpdateBuilder<'_> {
+		BadgeUpdateBuilder {
+			inner: self.inner.update_badge(badge_id),
+			runtime: &self.runtime,
+		}
+	}
+}
+
+impl Robolt<ApiKey> {
+	blocking_methods! {
+		fn publish_message(&self, universe_id: impl Into<UniverseId>, topic: &str, message: &str) -> Result<(), RoboltError>;
+	}
+
+	pub fn remove_api_key(self) -> Robolt<Unauthenticated> {
+		Robolt {
+			inner: self.inner.remove_api_key(),
+			runtime: self.runtime,
+		}
+	}
+
+	pub fn datastore_entry<T: DeserializeOwned>(
+		&self,
+		universe_id: impl Into<UniverseId>,
+		datastore: &str,
+		key: &str,
+	) -> Result<T, RoboltError> {
+		self.runtime
+			.block_on(self.inner.datastore_entry(universe_id, datastore, key))
+	}
+
+	pub fn set_datastore_entry<T: Serialize>(
+		&self,
+		universe_id: impl Into<UniverseId>,
+		datastore: &str,
+		key: &str,
+		value: T,
+	) -> Result<DataStoreEntryVersion, RoboltError> {
+		self.runtime
+			.block_on(self.inner.set_datastore_entry(universe_id, datastore, key, value))
+	}
+}
+
+#[cfg(feature = "oauth")]
+impl<State> Robolt<State> {
+	blocking_methods! {
+		fn exchange_code(&self, config: &OAuthConfig, code: &str, verifier: &str) -> Result<OAuthTokens, RoboltError>;
+		fn refresh_token(&self, config: &OAuthConfig, refresh_token: &str) -> Result<OAuthTokens, RoboltError>;
+		fn revoke_token(&self, config: &OAuthConfig, token: &str) -> Result<(), RoboltError>;
+	}
+
+	pub fn authorization_url(&self, config: &OAuthConfig, scopes: &[&str]) -> Authorization {
+		self.inner.authorization_url(config, scopes)
+	}
+}
+
+#[cfg(feature = "oauth")]
+impl Robolt<Unauthenticated> {
+	/// See [Robolt::set_oauth](crate::Robolt::set_oauth)
+	pub fn set_oauth(self, config: OAuthConfig, tokens: OAuthTokens) -> Robolt<OAuth> {
+		Robolt {
+			inner: self.inner.set_oauth(config, tokens),
+			runtime: self.runtime,
+		}
+	}
+}
+
+#[cfg(feature = "oauth")]
+impl Robolt<OAuth> {
+	blocking_methods! {
+		fn userinfo(&self) -> Result<UserInfo, RoboltError>;
+	}
+
+	pub fn tokens(&self) -> OAuthTokens {
+		self.inner.tokens()
+	}
+
+	pub fn remove_oauth(self) -> Robolt<Unauthenticated> {
+		Robolt {
+			inner: self.inner.remove_oauth(),
+			runtime: self.runtime,
+		}
+	}
+
+	pub fn revoke(self) -> Result<Robolt<Unauthenticated>, RoboltError> {
+		Ok(Robolt {
+			inner: self.runtime.block_on(self.inner.revoke())?,
+			runtime: self.runtime,
+		})
+	}
+}
+
+/// The synchronous counterpart of [RequestBuilder](crate::RequestBuilder)
+pub struct RequestBuilder<'a, State> {
+	inner: AsyncRequestBuilder<'a, State>,
+	runtime: &'a Runtime,
+}
+
+impl<'a, State> RequestBuilder<'a, State> {
+	pub fn method(mut self, method: Method) -> Self {
+		self.inner = self.inner.method(method);
+		self
+	}
+
+	pub fn query<K: AsRef<str>, V: ToString>(mut self, key: K, value: V) -> Self {
+		self.inner = self.inner.query(key, value);
+		self
+	}
+
+	pub fn send_body<T, U>(self, body: T) -> Result<U, RoboltError>
+	where
+		T: Serialize,
+		U: DeserializeOwned,
+	{
+		self.runtime.block_on(self.inner.send_body(body))
+	}
+
+	pub fn send<T>(self) -> Result<T, RoboltError>
+	where
+		T: DeserializeOwned,
+	{
+		self.runtime.block_on(self.inner.send())
+	}
+}
+
+/// The synchronous counterpart of
+/// [OutfitFilterBuilder](crate::api::avatar::OutfitFilterBuilder)
+pub struct OutfitFilterBuilder<'a, State> {
+	inner: AsyncOutfitFilterBuilder<'a, State>,
+	runtime: &'a Runtime,
+}
+
+impl<'a, State> OutfitFilterBuilder<'a, State> {
+	pub fn page(mut self, page: u8) -> Self {
+		self.inner = self.inner.page(page);
+		self
+	}
+
+	pub fn items_per_page(mut self, items_per_page: Limit) -> Self {
+		self.inner = self.inner.items_per_page(items_per_page);
+		self
+	}
+
+	pub fn editable(mut self, is_editable: bool) -> Self {
+		self.inner = self.inner.editable(is_editable);
+		self
+	}
+
+	pub fn send(self) -> Result<FilteredOutfitResponse, RoboltError> {
+		self.runtime.block_on(self.inner.send())
+	}
+}
+
+/// The synchronous counterpart of
+/// [BadgeUpdateBuilder](crate::api::badges::BadgeUpdateBuilder)
+pub struct BadgeUpdateBuilder<'a> {
+	inner: AsyncBadgeUpdateBuilder<'a>,
+	runtime: &'a Runtime,
+}
+
+impl<'a> BadgeUpdateBuilder<'a> {
+	pub fn name(mut self, name: &str) -> Self {
+		self.inner = self.inner.name(name);
+		self
+	}
+
+	pub fn description(mut self, description: &str) -> Self {
+		self.inner = self.inner.description(description);
+		self
+	}
+
+	pub fn enabled(mut self, enabled: bool) -> Self {
+		self.inner = self.inner.enabled(enabled);
+		self
+	}
+
+	pub fn update(self) -> Result<(), RoboltError> {
+		self.runtime.block_on(self.inner.update())
+	}
+}
+
+/// The synchronous counterpart of [Paginator](crate::pagination::Paginator)
+///
+/// Iterating over a [Paginator] walks every item across all pages.
+pub struct Paginator<'a, State, T> {
+	inner: AsyncPaginator<'a, State, T>,
+	runtime: &'a Runtime,
+}
+
+impl<'a, State, T> Paginator<'a, State, T>
+where
+	T: DeserializeOwned,
+{
+	fn new(inner: AsyncPaginator<'a, State, T>, runtime: &'a Runtime) -> Self {
+		Self {
+			inner,
+			runtime,
+		}
+	}
+
+	pub fn limit(mut self, limit: Limit) -> Self {
+		self.inner = self.inner.limit(limit);
+		self
+	}
+
+	pub fn page_size(mut self, page_size: u32) -> Self {
+		self.inner = self.inner.page_size(page_size);
+		self
+	}
+
+	pub fn sort_order(mut self, sort_order: SortOrder) -> Self {
+		self.inner = self.inner.sort_order(sort_order);
+		self
+	}
+
+	pub fn cursor<S: ToString>(mut self, cursor: S) -> Self {
+		self.inner = self.inner.cursor(cursor);
+		self
+	}
+
+	/// Fetches the page at the current cursor
+	pub fn page(&self) -> Result<Page<T>, RoboltError> {
+		self.runtime.block_on(self.inner.page())
+	}
+
+	/// Fetches the items of the page at the current cursor
+	pub fn send(&self) -> Result<Vec<T>, RoboltError> {
+		self.page().map(|page| page.data)
+	}
+}
+
+impl<'a, State, T> IntoIterator for Paginator<'a, State, T>
+where
+	T: DeserializeOwned,
+{
+	type IntoIter = PaginatorIter<'a, State, T>;
+	type Item = Result<T, RoboltError>;
+
+	fn into_iter(self) -> Self::IntoIter {
+		PaginatorIter {
+			paginator: Some(self),
+			items: VecDeque::new(),
+		}
+	}
+}
+
+/// Iterates over every item across all pages of a [Paginator]
+pub struct PaginatorIter<'a, State, T> {
+	paginator: Option<Paginator<'a, State, T>>,
+	items: VecDeque<T>,
+}
+
+impl<State, T> Iterator for PaginatorIter<'_, State, T>
+where
+	T: DeserializeOwned,
+{
+	type Item = Result<T, RoboltError>;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while self.items.is_empty() {
+			let paginator = self.paginator.take()?;
+			let page = match paginator.page() {
+				Ok(page) => page,
+				Err(err) => return Some(Err(err)),
+			};
+
+			self.paginator = page.next_page_cursor.map(|cursor| paginator.cursor(cursor));
+			self.items.extend(page.data);
+		}
+
+		self.items.pop_front().map(Ok)
+	}
+}
@@ -1,7 +1,17 @@
-#![feature(doc_cfg, core_intrinsics)]
+#![feature(doc_cfg)]
 
-pub use utils::client::Robolt;
-pub use utils::errors;
+pub use utils::builder::RoboltBuilder;
+pub use utils::cache::CachePolicy;
+pub use utils::client::{RequestBuilder, Robolt};
+pub use utils::coalesce::Coalescing;
+#[cfg(feature = "oauth")]
+pub use utils::oauth;
+pub use utils::rate_limit::{Rate, RateLimitPolicy};
+pub use utils::retry::RetryPolicy;
+pub use utils::{batch, cassette, challenge, errors, middleware, pagination, session, transport};
 
 pub mod api;
+#[cfg(feature = "blocking")]
+#[doc(cfg(feature = "blocking"))]
+pub mod blocking;
 mod utils;
@@ -0,0 +1,98 @@
+use std::future::Future;
+
+use futures::stream::{self, StreamExt};
+
+use crate::errors::RoboltError;
+use crate::Robolt;
+
+/// The number of chunks of a bulk lookup sent at the same time by default
+pub(crate) const DEFAULT_BATCH_CONCURRENCY: usize = 4;
+
+#[derive(Debug)]
+/// The outcome of a bulk lookup split into chunks
+///
+/// Roblox caps the number of inputs per request, so bulk lookups send their
+/// inputs in chunks. The items of the chunks that succeeded are merged in
+/// input order, while the chunks that failed are reported with their inputs.
+pub struct BatchResult<I, T> {
+	pub items: Vec<T>,
+	pub failed: Vec<FailedChunk<I>>,
+}
+
+#[derive(Debug)]
+/// A chunk of a bulk lookup that could not be fetched
+pub struct FailedChunk<I> {
+	pub inputs: Vec<I>,
+	pub error: RoboltError,
+}
+
+impl<I, T> BatchResult<I, T> {
+	/// Whether every chunk succeeded
+	pub fn is_complete(&self) -> bool {
+		self.failed.is_empty()
+	}
+
+	/// The inputs of every failed chunk
+	pub fn failed_inputs(&self) -> impl Iterator<Item = &I> {
+		self.failed.iter().flat_map(|chunk| chunk.inputs.iter())
+	}
+
+	/// The items, or the error of the first failed chunk
+	pub fn into_result(self) -> Result<Vec<T>, RoboltError> {
+		match self.failed.into_iter().next() {
+			Some(chunk) => Err(chunk.error),
+			None => Ok(self.items),
+		}
+	}
+}
+
+impl<State> Robolt<State> {
+	/// The number of chunks of a bulk lookup (e.g. [Robolt::users_from_ids])
+	/// sent at the same time
+	///
+	/// ### Arguments
+	/// * `batch_concurrency` - The maximum number of concurrent requests per
+	///   lookup (defaults to **4**)
+	pub fn with_batch_concurrency(mut self, batch_concurrency: usize) -> Self {
+		self.batch_concurrency = batch_concurrency.max(1);
+		self
+	}
+
+	/// Splits the inputs into chunks of at most `chunk_size` and sends them
+	/// with bounded concurrency
+	pub(crate) async fn chunked<I, T, F, Fut>(
+		&self,
+		inputs: Vec<I>,
+		chunk_size: usize,
+		send: F,
+	) -> BatchResult<I, T>
+	where
+		I: Clone,
+		F: Fn(Vec<I>) -> Fut,
+		Fut: Future<Output = Result<Vec<T>, RoboltError>>,
+	{
+		let mut result = BatchResult {
+			items: Vec::with_capacity(inputs.len()),
+			failed: Vec::new(),
+		};
+
+		let mut chunks = stream::iter(inputs.chunks(chunk_size).map(<[I]>::to_vec))
+			.map(|chunk| {
+				let res = send(chunk.clone());
+				async move { (chunk, res.await) }
+			})
+			.buffered(self.batch_concurrency);
+
+		while let Some((inputs, res)) = chunks.next().await {
+			match res {
+				Ok(items) => result.items.extend(items),
+				Err(error) => result.failed.push(FailedChunk {
+					inputs,
+					error,
+				}),
+			}
+		}
+
+		result
+	}
+}
@@ -0,0 +1,232 @@
+use std::marker::PhantomData;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use reqwest::header::{HeaderName, HeaderValue, ACCEPT_LANGUAGE, USER_AGENT};
+use reqwest::{Client, Proxy};
+
+use crate::api::routes::{BaseUrls, RobloxApi};
+use crate::errors::RoboltError;
+use crate::utils::batch::DEFAULT_BATCH_CONCURRENCY;
+use crate::utils::cache::{CachePolicy, ResponseCache};
+use crate::utils::cassette::{RecordingTransport, ReplayTransport};
+use crate::utils::client::{default_client_headers, Unauthenticated};
+use crate::utils::coalesce::Coalescing;
+use crate::utils::middleware::Middleware;
+use crate::utils::rate_limit::{RateLimitPolicy, RateLimiter};
+use crate::utils::retry::RetryPolicy;
+use crate::utils::transport::{ReqwestTransport, Transport};
+use crate::Robolt;
+
+impl Robolt {
+	pub fn builder() -> RoboltBuilder {
+		RoboltBuilder::new()
+	}
+}
+
+#[derive(Debug, Default)]
+/// Configures the HTTP client used by [Robolt]
+///
+/// Every option is kept when the client changes state through
+/// [Robolt::set_cookie] or [Robolt::remove_cookie].
+pub struct RoboltBuilder {
+	timeout: Option<Duration>,
+	connect_timeout: Option<Duration>,
+	proxies: Vec<Proxy>,
+	headers: Vec<(String, String)>,
+	base_urls: BaseUrls,
+	retry_policy: RetryPolicy,
+	cache_policy: Option<CachePolicy>,
+	rate_limit_policy: Option<RateLimitPolicy>,
+	batch_concurrency: Option<usize>,
+	coalescing: Coalescing,
+	transport: Option<Arc<dyn Transport>>,
+	middleware: Vec<Arc<dyn Middleware>>,
+	cassette: Option<CassetteMode>,
+}
+
+#[derive(Debug)]
+enum CassetteMode {
+	Record(PathBuf),
+	Replay(PathBuf),
+}
+
+impl RoboltBuilder {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// The total time allowed for a request, from connecting until the
+	/// response body has been read
+	pub fn timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = Some(timeout);
+		self
+	}
+
+	/// The time allowed for establishing a connection
+	pub fn connect_timeout(mut self, connect_timeout: Duration) -> Self {
+		self.connect_timeout = Some(connect_timeout);
+		self
+	}
+
+	/// Routes requests through a HTTP(S) or SOCKS proxy
+	///
+	/// ### Arguments
+	/// * `proxy` - The proxy to use (e.g.
+	///   `Proxy::all("socks5://127.0.0.1:1080")`)
+	pub fn proxy(mut self, proxy: Proxy) -> Self {
+		self.proxies.push(proxy);
+		self
+	}
+
+	/// Replaces the default `robolt/<version>` user agent
+	pub fn user_agent<S: ToString>(self, user_agent: S) -> Self {
+		self.default_header(USER_AGENT.as_str(), user_agent)
+	}
+
+	/// The languages requested through the `Accept-Language` header (e.g.
+	/// `en-US`)
+	pub fn accept_language<S: ToString>(self, accept_language: S) -> Self {
+		self.default_header(ACCEPT_LANGUAGE.as_str(), accept_language)
+	}
+
+	/// Adds a header sent with every request, replacing any default header
+	/// with the same name
+	pub fn default_header<K: ToString, V: ToString>(mut self, name: K, value: V) -> Self {
+		self.headers.push((name.to_string(), value.to_string()));
+		self
+	}
+
+	/// See [Robolt::with_base_url]
+	pub fn base_url<S: ToString>(mut self, domain: RobloxApi, base_url: S) -> Self {
+		self.base_urls.set(domain, base_url.to_string());
+		self
+	}
+
+	/// See [Robolt::with_global_base_url]
+	pub fn global_base_url<S: ToString>(mut self, base_url: S) -> Self {
+		self.base_urls.set_global(base_url.to_string());
+		self
+	}
+
+	/// See [Robolt::with_retry_policy]
+	pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.retry_policy = retry_policy;
+		self
+	}
+
+	/// See [Robolt::with_cache]
+	pub fn cache(mut self, cache_policy: CachePolicy) -> Self {
+		self.cache_policy = Some(cache_policy);
+		self
+	}
+
+	/// See [Robolt::with_rate_limit]
+	pub fn rate_limit(mut self, rate_limit_policy: RateLimitPolicy) -> Self {
+		self.rate_limit_policy = Some(rate_limit_policy);
+		self
+	}
+
+	/// See [Robolt::with_batch_concurrency]
+	pub fn batch_concurrency(mut self, batch_concurrency: usize) -> Self {
+		self.batch_concurrency = Some(batch_concurrency.max(1));
+		self
+	}
+
+	/// See [Robolt::with_coalescing]
+	pub fn coalescing(mut self, coalescing: Coalescing) -> Self {
+		self.coalescing = coalescing;
+		self
+	}
+
+	/// Sends requests through a custom [Transport] instead of [reqwest]
+	///
+	/// The timeout and proxy options only apply to the default transport.
+	pub fn transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+		self.transport = Some(Arc::new(transport));
+		self
+	}
+
+	/// See [Robolt::with_middleware]
+	pub fn middleware<M: Middleware + 'static>(mut self, middleware: M) -> Self {
+		self.middleware.push(Arc::new(middleware));
+		self
+	}
+
+	/// Writes every request and response to a cassette file, see
+	/// [RecordingTransport]
+	pub fn record<P: Into<PathBuf>>(mut self, path: P) -> Self {
+		self.cassette = Some(CassetteMode::Record(path.into()));
+		self
+	}
+
+	/// Serves the responses of a cassette file written by
+	/// [RoboltBuilder::record] instead of sending requests, see
+	/// [ReplayTransport]
+	pub fn replay<P: Into<PathBuf>>(mut self, path: P) -> Self {
+		self.cassette = Some(CassetteMode::Replay(path.into()));
+		self
+	}
+
+	pub fn build(self) -> Result<Robolt, RoboltError> {
+		let mut headers = default_client_headers();
+
+		for (name, value) in self.headers {
+			let name = HeaderName::try_from(name.as_str())
+				.map_err(|_| RoboltError::Validation(format!("Invalid header name: {name}")))?;
+			let value = HeaderValue::try_from(value.as_str())
+				.map_err(|_| RoboltError::Validation(format!("Invalid value for header {name}")))?;
+
+			headers.insert(name, value);
+		}
+
+		let mut client = Client::builder().default_headers(headers.clone());
+
+		if let Some(timeout) = self.timeout {
+			client = client.timeout(timeout);
+		}
+
+		if let Some(connect_timeout) = self.connect_timeout {
+			client = client.connect_timeout(connect_timeout);
+		}
+
+		for proxy in self.proxies {
+			client = client.proxy(proxy);
+		}
+
+		let http = client.build()?;
+		let mut transport = self
+			.transport
+			.unwrap_or_else(|| Arc::new(ReqwestTransport::new(http.clone())));
+
+		match self.cassette {
+			Some(CassetteMode::Record(path)) => {
+				transport = Arc::new(RecordingTransport::from_arc(transport, path))
+			},
+			Some(CassetteMode::Replay(path)) => transport = Arc::new(ReplayTransport::load(path)?),
+			None => {},
+		}
+
+		Ok(Robolt {
+			state: PhantomData::<Unauthenticated>,
+			http,
+			transport,
+			headers,
+			middleware: self.middleware,
+			base_urls: self.base_urls,
+			retry_policy: self.retry_policy,
+			cache: self.cache_policy.map(|policy| Arc::new(ResponseCache::new(policy))),
+			rate_limiter: self.rate_limit_policy.map(|policy| Arc::new(RateLimiter::new(policy))),
+			batch_concurrency: self.batch_concurrency.unwrap_or(DEFAULT_BATCH_CONCURRENCY),
+			coalescing: self.coalescing,
+			in_flight: Arc::default(),
+			cookie: None,
+			xcsrf: Arc::default(),
+			session_info: None,
+			api_key: None,
+			#[cfg(feature = "oauth")]
+			oauth: None,
+		})
+	}
+}
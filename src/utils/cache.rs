@@ -0,0 +1,249 @@
+use std::collections::HashMap;
+use std::num::NonZeroUsize;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use lru::LruCache;
+use reqwest::Method;
+
+use crate::api::routes::RobloxApi;
+use crate::utils::trace::template_path;
+use crate::Robolt;
+
+#[derive(Debug, Clone, PartialEq)]
+/// Controls which successful responses are cached in memory and for how long
+///
+/// Endpoints are identified by their domain and path, with numeric segments
+/// written as `{id}` (e.g. `/v1/users/{id}`). A TTL of zero disables caching
+/// for the endpoints it applies to.
+///
+/// ### Defaults
+///
+/// * **1000** responses, the least recently used being evicted first
+/// * **60s** TTL
+/// * Only unauthenticated `GET` requests are cached
+pub struct CachePolicy {
+	capacity: usize,
+	default_ttl: Duration,
+	domain_ttls: HashMap<RobloxApi, Duration>,
+	endpoint_ttls: HashMap<(RobloxApi, String), Duration>,
+	cache_authenticated: bool,
+	cache_mutating: bool,
+}
+
+impl Default for CachePolicy {
+	fn default() -> Self {
+		Self {
+			capacity: 1000,
+			default_ttl: Duration::from_secs(60),
+			domain_ttls: HashMap::new(),
+			endpoint_ttls: HashMap::new(),
+			cache_authenticated: false,
+			cache_mutating: false,
+		}
+	}
+}
+
+impl CachePolicy {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// The maximum number of cached responses
+	pub fn capacity(mut self, capacity: usize) -> Self {
+		self.capacity = capacity.max(1);
+		self
+	}
+
+	/// The TTL of endpoints without a more specific one
+	pub fn default_ttl(mut self, ttl: Duration) -> Self {
+		self.default_ttl = ttl;
+		self
+	}
+
+	/// The TTL of every endpoint of a domain
+	pub fn domain_ttl(mut self, domain: RobloxApi, ttl: Duration) -> Self {
+		self.domain_ttls.insert(domain, ttl);
+		self
+	}
+
+	/// The TTL of a single endpoint, taking precedence over the domain TTL
+	///
+	/// ### Arguments
+	/// * `domain` - The domain of the endpoint
+	/// * `path` - The path of the endpoint (e.g. `/v1/users/{id}`)
+	/// * `ttl` - How long responses are kept
+	pub fn endpoint_ttl<S: ToString>(mut self, domain: RobloxApi, path: S, ttl: Duration) -> Self {
+		self.endpoint_ttls.insert((domain, path.to_string()), ttl);
+		self
+	}
+
+	/// Also caches requests sent with a cookie, keyed by the cookie so that
+	/// accounts never see each other's responses
+	pub fn cache_authenticated(mut self, cache_authenticated: bool) -> Self {
+		self.cache_authenticated = cache_authenticated;
+		self
+	}
+
+	/// Also caches requests other than `GET` (e.g. the `POST` batch lookups),
+	/// keyed by their body
+	pub fn cache_mutating(mut self, cache_mutating: bool) -> Self {
+		self.cache_mutating = cache_mutating;
+		self
+	}
+
+	fn ttl(&self, domain: RobloxApi, path: &str) -> Option<Duration> {
+		let ttl = self
+			.endpoint_ttls
+			.get(&(domain, template_path(path)))
+			.or_else(|| self.domain_ttls.get(&domain))
+			.unwrap_or(&self.default_ttl);
+
+		Some(*ttl).filter(|ttl| !ttl.is_zero())
+	}
+}
+
+#[derive(Debug)]
+/// The responses cached by a client, shared between its clones
+pub(crate) struct ResponseCache {
+	policy: CachePolicy,
+	entries: Mutex<LruCache<CacheKey, CacheEntry>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct CacheKey {
+	method: Method,
+	url: String,
+	body: Option<Vec<u8>>,
+	/// The cookie the request was sent with
+	identity: Option<Arc<str>>,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+	body: Vec<u8>,
+	expires_at: Instant,
+}
+
+impl ResponseCache {
+	pub(crate) fn new(policy: CachePolicy) -> Self {
+		let capacity = NonZeroUsize::new(policy.capacity).unwrap_or(NonZeroUsize::MIN);
+
+		Self {
+			policy,
+			entries: Mutex::new(LruCache::new(capacity)),
+		}
+	}
+
+	/// The key and TTL of a request, or `None` if the policy does not allow
+	/// caching it
+	pub(crate) fn key(
+		&self,
+		domain: RobloxApi,
+		path: &str,
+		method: &Method,
+		url: &str,
+		body: Option<&[u8]>,
+		cookie: Option<&str>,
+	) -> Option<(CacheKey, Duration)> {
+		if *method != Method::GET && !self.policy.cache_mutating {
+			return None;
+		}
+
+		if cookie.is_some() && !self.policy.cache_authenticated {
+			return None;
+		}
+
+		let ttl = self.policy.ttl(domain, path)?;
+		let key = CacheKey {
+			method: method.clone(),
+			url: url.to_string(),
+			body: body.map(<[u8]>::to_vec),
+			identity: cookie.map(Arc::from),
+		};
+
+		Some((key, ttl))
+	}
+
+	pub(crate) fn get(&self, key: &CacheKey) -> Option<Vec<u8>> {
+		let mut entries = self.entries.lock().unwrap();
+
+		match entries.get(key) {
+			Some(entry) if entry.expires_at > Instant::now() => Some(entry.body.clone()),
+			Some(_) => {
+				entries.pop(key);
+				None
+			},
+			None => None,
+		}
+	}
+
+	pub(crate) fn insert(&self, key: CacheKey, body: Vec<u8>, ttl: Duration) {
+		let entry = CacheEntry {
+			body,
+			expires_at: Instant::now() + ttl,
+		};
+
+		self.entries.lock().unwrap().put(key, entry);
+	}
+
+	fn remove_where<F: Fn(&CacheKey) -> bool>(&self, predicate: F) {
+		let mut entries = self.entries.lock().unwrap();
+		let keys = entries
+			.iter()
+			.map(|(key, _)| key)
+			.filter(|key| predicate(key))
+			.cloned()
+			.collect::<Vec<CacheKey>>();
+
+		for key in keys {
+			entries.pop(&key);
+		}
+	}
+}
+
+impl<State> Robolt<State> {
+	/// Enables the response cache, replacing any existing cache
+	///
+	/// The cache is shared between clones of the client and kept when it
+	/// changes state.
+	///
+	/// ### Arguments
+	/// * `cache_policy` - Which responses to cache and for how long
+	pub fn with_cache(mut self, cache_policy: CachePolicy) -> Self {
+		self.cache = Some(Arc::new(ResponseCache::new(cache_policy)));
+		self
+	}
+
+	/// Removes every cached response
+	pub fn clear_cache(&self) {
+		if let Some(cache) = &self.cache {
+			cache.entries.lock().unwrap().clear();
+		}
+	}
+
+	/// Removes the cached responses of an endpoint, whatever their query
+	///
+	/// ### Arguments
+	/// * `domain` - The domain of the endpoint
+	/// * `path` - The path of the endpoint (e.g. `/v1/users/1`)
+	pub fn invalidate_cache<S: AsRef<str>>(&self, domain: RobloxApi, path: S) {
+		if let Some(cache) = &self.cache {
+			let url = format!("{}{}", self.base_urls.resolve(&domain), path.as_ref());
+
+			cache.remove_where(|key| {
+				key.url
+					.strip_prefix(&url)
+					.is_some_and(|rest| rest.is_empty() || rest.starts_with('?'))
+			});
+		}
+	}
+
+	/// Removes the cached responses of every endpoint of a domain
+	pub fn invalidate_domain_cache(&self, domain: RobloxApi) {
+		if let Some(cache) = &self.cache {
+			let base_url = format!("{}/", self.base_urls.resolve(&domain));
+			cache.remove_where(|key| key.url.starts_with(&base_url));
+		}
+	}
+}
@@ -0,0 +1,317 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use futures::future::BoxFuture;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, AUTHORIZATION, CONTENT_TYPE, COOKIE, SET_COOKIE};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use url::form_urlencoded;
+
+use crate::errors::RoboltError;
+use crate::transport::{HttpRequest, HttpResponse, Transport};
+use crate::utils::client::{API_KEY_HEADER, XCSRF_HEADER};
+
+/// The value stored in place of redacted header values and body fields
+const REDACTED: &str = "[REDACTED]";
+
+/// The JSON and form fields holding passwords, tokens or client secrets,
+/// redacted from request and response bodies
+const SECRET_FIELDS: [&str; 11] = [
+	"password",
+	"code",
+	"token",
+	"verificationToken",
+	"reauthenticationToken",
+	"challengeMetadata",
+	"access_token",
+	"refresh_token",
+	"id_token",
+	"client_secret",
+	"code_verifier",
+];
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+/// A list of recorded request/response pairs, stored as JSON
+pub struct Cassette {
+	pub interactions: Vec<Interaction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Interaction {
+	pub request: RecordedRequest,
+	pub response: RecordedResponse,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedRequest {
+	pub method: String,
+	pub url: String,
+	/// Request headers, with `Cookie`, `Authorization`, `x-api-key` and
+	/// `x-csrf-token` redacted
+	///
+	/// Headers are not taken into account when matching requests.
+	pub headers: BTreeMap<String, String>,
+	/// The body, with the string values of known secret JSON or form fields
+	/// (e.g. `password`, `refresh_token`, `client_secret`) redacted
+	pub body: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedResponse {
+	pub status: u16,
+	/// Response headers, with `Set-Cookie` and `x-csrf-token` redacted
+	pub headers: BTreeMap<String, String>,
+	/// The body, with the same fields redacted as in [RecordedRequest::body]
+	pub body: String,
+}
+
+impl Cassette {
+	pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, RoboltError> {
+		let path = path.as_ref();
+		let contents = fs::read(path).map_err(|err| cassette_error(path, err))?;
+
+		serde_json::from_slice(&contents).map_err(|err| cassette_error(path, err))
+	}
+
+	pub fn save<P: AsRef<Path>>(&self, path: P) -> Result<(), RoboltError> {
+		let path = path.as_ref();
+		let contents = serde_json::to_vec_pretty(self).map_err(|err| cassette_error(path, err))?;
+
+		fs::write(path, contents).map_err(|err| cassette_error(path, err))
+	}
+}
+
+impl RecordedRequest {
+	fn new(req: &HttpRequest) -> Self {
+		Self {
+			method: req.method.to_string(),
+			url: req.url.clone(),
+			headers: record_headers(&req.headers),
+			body: req.body.as_deref().map(|body| record_body(body, &req.headers)),
+		}
+	}
+
+	fn matches(&self, other: &RecordedRequest) -> bool {
+		self.method == other.method && self.url == other.url && self.body == other.body
+	}
+}
+
+impl RecordedResponse {
+	fn new(res: &HttpResponse) -> Self {
+		Self {
+			status: res.status.as_u16(),
+			headers: record_headers(&res.headers),
+			body: record_body(&res.body, &res.headers),
+		}
+	}
+
+	fn to_response(&self) -> Result<HttpResponse, RoboltError> {
+		let status = StatusCode::from_u16(self.status)
+			.map_err(|_| RoboltError::Transport(format!("Invalid recorded status: {}", self.status)))?;
+		let mut headers = HeaderMap::new();
+
+		for (name, value) in &self.headers {
+			if let (Ok(name), Ok(value)) = (
+				HeaderName::try_from(name.as_str()),
+				HeaderValue::try_from(value.as_str()),
+			) {
+				headers.insert(name, value);
+			}
+		}
+
+		Ok(HttpResponse {
+			status,
+			headers,
+			body: self.body.clone().into_bytes(),
+		})
+	}
+}
+
+#[derive(Debug, Clone)]
+/// Forwards requests to another [Transport] and writes every interaction to
+/// a cassette file
+///
+/// The file is rewritten after every response, so the cassette is complete
+/// even if the process exits early. The `Cookie`, `Set-Cookie`,
+/// `Authorization`, `x-api-key` and `x-csrf-token` headers are redacted, as
+/// are known secret fields of JSON and form bodies. Other secrets an
+/// endpoint may return are stored as is, so cassettes should be reviewed
+/// before they are committed.
+pub struct RecordingTransport {
+	inner: Arc<dyn Transport>,
+	path: PathBuf,
+	cassette: Arc<Mutex<Cassette>>,
+}
+
+impl RecordingTransport {
+	pub fn new<T: Transport + 'static, P: Into<PathBuf>>(inner: T, path: P) -> Self {
+		Self::from_arc(Arc::new(inner), path.into())
+	}
+
+	pub(crate) fn from_arc(inner: Arc<dyn Transport>, path: PathBuf) -> Self {
+		Self {
+			inner,
+			path,
+			cassette: Arc::default(),
+		}
+	}
+}
+
+impl Transport for RecordingTransport {
+	fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, RoboltError>> {
+		Box::pin(async move {
+			let recorded = RecordedRequest::new(&request);
+			let res = self.inner.send(request).await?;
+
+			// The file is written under the lock so that a snapshot is never
+			// overwritten by an older one
+			let mut cassette = self.cassette.lock().unwrap();
+			cassette.interactions.push(Interaction {
+				request: recorded,
+				response: RecordedResponse::new(&res),
+			});
+			cassette.save(&self.path)?;
+
+			Ok(res)
+		})
+	}
+}
+
+#[derive(Debug, Clone)]
+/// Serves the interactions of a cassette instead of sending requests
+///
+/// Requests are matched by method, URL and body. Each interaction is served
+/// once, in the order it was recorded, and requests left without a match
+/// fail with [RoboltError::Transport].
+pub struct ReplayTransport {
+	interactions: Arc<Mutex<Vec<Option<Interaction>>>>,
+}
+
+impl ReplayTransport {
+	pub fn new(cassette: Cassette) -> Self {
+		Self {
+			interactions: Arc::new(Mutex::new(cassette.interactions.into_iter().map(Some).collect())),
+		}
+	}
+
+	pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, RoboltError> {
+		Cassette::load(path).map(Self::new)
+	}
+}
+
+impl Transport for ReplayTransport {
+	fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, RoboltError>> {
+		let recorded = RecordedRequest::new(&request);
+		let mut interactions = self.interactions.lock().unwrap();
+
+		let response = interactions
+			.iter_mut()
+			.find(|interaction| matches!(interaction, Some(interaction) if interaction.request.matches(&recorded)))
+			.and_then(Option::take)
+			.ok_or_else(|| {
+				RoboltError::Transport(format!(
+					"No recorded interaction for {} {}",
+					request.method, request.url
+				))
+			})
+			.and_then(|interaction| interaction.response.to_response());
+
+		Box::pin(async move { response })
+	}
+}
+
+/// Flattens headers into a map, joining repeated headers and redacting
+/// credentials
+fn record_headers(headers: &HeaderMap) -> BTreeMap<String, String> {
+	let mut recorded = BTreeMap::<String, String>::new();
+
+	for (name, value) in headers {
+		let redacted =
+			[COOKIE, SET_COOKIE, AUTHORIZATION].contains(name) || name == API_KEY_HEADER || name == XCSRF_HEADER;
+		let value = if redacted {
+			REDACTED.to_string()
+		} else {
+			String::from_utf8_lossy(value.as_bytes()).into_owned()
+		};
+
+		recorded
+			.entry(name.to_string())
+			.and_modify(|existing| {
+				existing.push_str(", ");
+				existing.push_str(&value);
+			})
+			.or_insert(value);
+	}
+
+	recorded
+}
+
+/// Decodes a body as text, redacting the string values of known secret
+/// fields when it is JSON or a form
+///
+/// Bodies without secrets are kept byte for byte.
+fn record_body(body: &[u8], headers: &HeaderMap) -> String {
+	let text = String::from_utf8_lossy(body).into_owned();
+	let is_form = headers
+		.get(CONTENT_TYPE)
+		.and_then(|v| v.to_str().ok())
+		.is_some_and(|v| v.starts_with("application/x-www-form-urlencoded"));
+
+	if is_form {
+		let mut redacted = false;
+		let pairs = form_urlencoded::parse(body)
+			.map(|(key, value)| {
+				if SECRET_FIELDS.contains(&key.as_ref()) {
+					redacted = true;
+					(key, REDACTED.into())
+				} else {
+					(key, value)
+				}
+			})
+			.collect::<Vec<_>>();
+
+		if redacted {
+			return form_urlencoded::Serializer::new(String::new())
+				.extend_pairs(pairs)
+				.finish();
+		}
+	} else if let Ok(mut value) = serde_json::from_slice::<Value>(body) {
+		if redact_json(&mut value) {
+			return value.to_string();
+		}
+	}
+
+	text
+}
+
+/// Replaces the string values of secret fields, returning whether any were
+/// found
+fn redact_json(value: &mut Value) -> bool {
+	match value {
+		Value::Object(fields) => {
+			let mut redacted = false;
+
+			for (key, value) in fields.iter_mut() {
+				if SECRET_FIELDS.contains(&key.as_str()) && value.is_string() {
+					*value = Value::String(REDACTED.to_string());
+					redacted = true;
+				} else {
+					redacted |= redact_json(value);
+				}
+			}
+
+			redacted
+		},
+		Value::Array(values) => values
+			.iter_mut()
+			.fold(false, |redacted, value| redact_json(value) | redacted),
+		_ => false,
+	}
+}
+
+fn cassette_error<E: std::fmt::Display>(path: &Path, err: E) -> RoboltError {
+	RoboltError::Transport(format!("Cassette {}: {err}", path.display()))
+}
@@ -0,0 +1,298 @@
+use std::fmt;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Method, StatusCode};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Deserializer};
+use serde_json::{json, Value};
+
+use crate::api::ids::UserId;
+use crate::api::routes::RobloxApi;
+use crate::errors::RoboltError;
+use crate::transport::HttpResponse;
+use crate::utils::client::Authenticated;
+use crate::Robolt;
+
+const CHALLENGE_ID_HEADER: &str = "rblx-challenge-id";
+const CHALLENGE_TYPE_HEADER: &str = "rblx-challenge-type";
+const CHALLENGE_METADATA_HEADER: &str = "rblx-challenge-metadata";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The kind of challenge Roblox asks for
+pub enum ChallengeType {
+	TwoStepVerification,
+	Captcha,
+	Reauthentication,
+	/// A value unknown to this version of the crate
+	Unknown(String),
+}
+
+impl ChallengeType {
+	pub fn as_str(&self) -> &str {
+		match self {
+			ChallengeType::TwoStepVerification => "twostepverification",
+			ChallengeType::Captcha => "captcha",
+			ChallengeType::Reauthentication => "reauthentication",
+			ChallengeType::Unknown(kind) => kind,
+		}
+	}
+}
+
+impl From<&str> for ChallengeType {
+	fn from(kind: &str) -> Self {
+		match kind {
+			"twostepverification" => ChallengeType::TwoStepVerification,
+			"captcha" => ChallengeType::Captcha,
+			"reauthentication" => ChallengeType::Reauthentication,
+			kind => ChallengeType::Unknown(kind.to_string()),
+		}
+	}
+}
+
+impl fmt::Display for ChallengeType {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A challenge Roblox requires to be solved before it accepts a request,
+/// returned as [RoboltError::Challenge]
+pub struct Challenge {
+	pub id: String,
+	pub kind: ChallengeType,
+	/// The decoded `rblx-challenge-metadata` header ([Value::Null] if it was
+	/// missing or not base64 encoded JSON)
+	pub metadata: Value,
+	pub status: StatusCode,
+	pub(crate) request: PendingRequest,
+}
+
+#[derive(Clone, PartialEq)]
+/// The request that was challenged, kept so that it can be resubmitted
+pub(crate) struct PendingRequest {
+	pub(crate) domain: RobloxApi,
+	pub(crate) method: Method,
+	pub(crate) endpoint: String,
+	pub(crate) body: Option<Vec<u8>>,
+}
+
+impl fmt::Debug for PendingRequest {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("PendingRequest")
+			.field("domain", &self.domain)
+			.field("method", &self.method)
+			.field("endpoint", &self.endpoint)
+			.field("body", &self.body.as_ref().map(|_| "[REDACTED]"))
+			.finish()
+	}
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// The metadata of a two-step verification challenge
+pub struct TwoStepVerificationMetadata {
+	#[serde(deserialize_with = "string_or_number")]
+	pub user_id: UserId,
+	/// The ID of the two-step verification challenge, which differs from the
+	/// ID of the [Challenge]
+	pub challenge_id: String,
+	pub action_type: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct VerificationResponse {
+	verification_token: String,
+}
+
+#[derive(Deserialize)]
+struct ReauthenticationResponse {
+	token: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Where the user received their two-step verification code
+pub enum TwoStepVerificationMedia {
+	Authenticator,
+	Email,
+	Sms,
+	RecoveryCode,
+}
+
+impl TwoStepVerificationMedia {
+	fn path(&self) -> &str {
+		match self {
+			TwoStepVerificationMedia::Authenticator => "authenticator",
+			TwoStepVerificationMedia::Email => "email",
+			TwoStepVerificationMedia::Sms => "sms",
+			TwoStepVerificationMedia::RecoveryCode => "recovery-codes",
+		}
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The metadata proving that a [Challenge] was solved
+pub struct ChallengeSolution {
+	pub metadata: Value,
+}
+
+impl ChallengeSolution {
+	/// A solution obtained outside of the crate, e.g. a captcha token
+	///
+	/// ### Arguments
+	/// * `metadata` - The metadata expected by Roblox for the challenge type
+	pub fn new(metadata: Value) -> Self {
+		Self {
+			metadata,
+		}
+	}
+}
+
+impl Challenge {
+	pub(crate) fn from_response(res: &HttpResponse, request: impl FnOnce() -> PendingRequest) -> Option<Self> {
+		let header = |name| res.headers.get(name).and_then(|v| v.to_str().ok());
+		let id = header(CHALLENGE_ID_HEADER)?;
+		let metadata = header(CHALLENGE_METADATA_HEADER)
+			.and_then(|metadata| STANDARD.decode(metadata).ok())
+			.and_then(|metadata| serde_json::from_slice(&metadata).ok())
+			.unwrap_or_default();
+
+		Some(Self {
+			id: id.to_string(),
+			kind: header(CHALLENGE_TYPE_HEADER).unwrap_or_default().into(),
+			metadata,
+			status: res.status,
+			request: request(),
+		})
+	}
+
+	/// The metadata of a two-step verification challenge, if it is one
+	pub fn two_step_verification(&self) -> Option<TwoStepVerificationMetadata> {
+		match self.kind {
+			ChallengeType::TwoStepVerification => serde_json::from_value(self.metadata.clone()).ok(),
+			_ => None,
+		}
+	}
+}
+
+impl Robolt<Authenticated> {
+	/// Verifies a two-step verification code
+	///
+	/// ### Arguments
+	/// * `challenge` - A [ChallengeType::TwoStepVerification] challenge
+	/// * `media` - Where the user received the code
+	/// * `code` - The code entered by the user
+	pub async fn solve_two_step_verification(
+		&self,
+		challenge: &Challenge,
+		media: TwoStepVerificationMedia,
+		code: &str,
+	) -> Result<ChallengeSolution, RoboltError> {
+		let metadata = challenge.two_step_verification().ok_or_else(|| {
+			RoboltError::Validation("The challenge is not a two-step verification challenge".to_string())
+		})?;
+
+		let res = self
+			.request(
+				RobloxApi::TwoStepVerification,
+				format!("/v1/users/{}/challenges/{}/verify", metadata.user_id, media.path()),
+			)
+			.method(Method::POST)
+			.send_body::<_, VerificationResponse>(json!({
+				"challengeId": metadata.challenge_id,
+				"actionType": metadata.action_type,
+				"code": code,
+			}))
+			.await?;
+
+		Ok(ChallengeSolution::new(json!({
+			"verificationToken": res.verification_token,
+			"rememberDevice": false,
+			"challengeId": metadata.challenge_id,
+			"actionType": metadata.action_type,
+		})))
+	}
+
+	/// Confirms the password of the authenticated user
+	///
+	/// ### Arguments
+	/// * `challenge` - A [ChallengeType::Reauthentication] challenge
+	/// * `password` - The password of the authenticated user
+	pub async fn solve_reauthentication(
+		&self,
+		challenge: &Challenge,
+		password: &str,
+	) -> Result<ChallengeSolution, RoboltError> {
+		if challenge.kind != ChallengeType::Reauthentication {
+			return Err(RoboltError::Validation(
+				"The challenge is not a reauthentication challenge".to_string(),
+			));
+		}
+
+		let res = self
+			.request(RobloxApi::Challenge, "/reauthentication-service/v1/token/generate")
+			.method(Method::POST)
+			.send_body::<_, ReauthenticationResponse>(json!({ "password": password }))
+			.await?;
+
+		Ok(ChallengeSolution::new(json!({ "reauthenticationToken": res.token })))
+	}
+}
+
+impl<State> Robolt<State> {
+	/// Completes a solved challenge and sends the challenged request again
+	/// with the solution, decoding the response into `T` (which can be a
+	/// [serde_json::Value])
+	///
+	/// ### Arguments
+	/// * `challenge` - The challenge returned as [RoboltError::Challenge]
+	/// * `solution` - The solution of the challenge
+	pub async fn resubmit<T: DeserializeOwned>(
+		&self,
+		challenge: &Challenge,
+		solution: &ChallengeSolution,
+	) -> Result<T, RoboltError> {
+		self.request(RobloxApi::Challenge, "/challenge/v1/continue")
+			.method(Method::POST)
+			.send_body::<_, Value>(json!({
+				"challengeId": challenge.id,
+				"challengeType": challenge.kind.as_str(),
+				"challengeMetadata": solution.metadata.to_string(),
+			}))
+			.await?;
+
+		let metadata = STANDARD.encode(solution.metadata.to_string());
+		let mut headers = HeaderMap::new();
+
+		for (name, value) in [
+			(CHALLENGE_ID_HEADER, challenge.id.as_str()),
+			(CHALLENGE_TYPE_HEADER, challenge.kind.as_str()),
+			(CHALLENGE_METADATA_HEADER, &metadata),
+		] {
+			let value = HeaderValue::from_str(value).map_err(|_| {
+				RoboltError::Validation(format!("The {name} header contains characters that are not allowed"))
+			})?;
+			headers.insert(HeaderName::from_static(name), value);
+		}
+
+		self.resend(&challenge.request, &headers).await
+	}
+}
+
+/// Roblox sends some IDs as strings and others as numbers
+fn string_or_number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UserId, D::Error> {
+	#[derive(Deserialize)]
+	#[serde(untagged)]
+	enum Id {
+		String(String),
+		Number(u64),
+	}
+
+	match Id::deserialize(deserializer)? {
+		Id::String(id) => id.parse().map(UserId).map_err(serde::de::Error::custom),
+		Id::Number(id) => Ok(UserId(id)),
+	}
+}
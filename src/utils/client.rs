@@ -1,14 +1,30 @@
-use std::error::Error;
 use std::fmt::{Debug, Display};
 use std::marker::PhantomData;
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
 
+#[cfg(feature = "oauth")]
+use reqwest::header::AUTHORIZATION;
 use reqwest::header::{HeaderMap, ACCEPT, CONTENT_LENGTH, CONTENT_TYPE, COOKIE, USER_AGENT};
-use reqwest::{Client, Method};
+use reqwest::{Client, Method, StatusCode};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
+use tokio::time::sleep;
+use url::form_urlencoded::byte_serialize;
 
-use crate::api::routes::RobloxApi;
-use crate::utils::errors::{RobloxAPIErrors, RoboltError};
+use crate::api::routes::{BaseUrls, RobloxApi};
+use crate::utils::cache::ResponseCache;
+use crate::utils::challenge::{Challenge, PendingRequest};
+use crate::utils::coalesce::{Coalescing, InFlight};
+use crate::utils::errors::RoboltError;
+use crate::utils::middleware::Middleware;
+#[cfg(feature = "oauth")]
+use crate::utils::oauth::OAuthSession;
+use crate::utils::rate_limit::RateLimiter;
+use crate::utils::retry::RetryPolicy;
+use crate::utils::session::SessionInfo;
+use crate::utils::trace;
+use crate::utils::transport::{HttpRequest, HttpResponse, Transport};
 
 impl Default for Robolt {
 	fn default() -> Self {
@@ -18,31 +34,70 @@ impl Default for Robolt {
 
 impl Robolt {
 	pub fn new() -> Self {
-		let client = Client::builder()
-			.default_headers(default_client_headers())
-			.build()
-			.expect("Failed to build client");
-
-		Self {
-			state: PhantomData::<Unauthenticated>,
-			http: client,
-			cookie: None,
-			xcsrf: None,
-		}
+		Self::builder().build().expect("Failed to build client")
 	}
 
-	pub async fn from(roblox_cookie: String) -> Result<Robolt<Authenticated>, Box<dyn Error>> {
+	pub async fn from(roblox_cookie: String) -> Result<Robolt<Authenticated>, RoboltError> {
 		Self::new().set_cookie(roblox_cookie).await
 	}
 }
 
 impl<State> Robolt<State> {
-	pub(crate) fn request<S: ToString + Display>(&self, domain: RobloxApi, path: S) -> RequestBuilder<'_, State> {
-		RequestBuilder::new(domain.url(), path, self)
+	/// Overrides the scheme and host used for requests to the given domain
+	///
+	/// ### Arguments
+	/// * `domain` - The domain to override
+	/// * `base_url` - The scheme and host to use instead (e.g. `http://127.0.0.1:8080`)
+	pub fn with_base_url<S: ToString>(mut self, domain: RobloxApi, base_url: S) -> Self {
+		self.base_urls.set(domain, base_url.to_string());
+		self
+	}
+
+	/// Overrides the scheme and host used for requests to every domain that
+	/// does not have its own override
+	///
+	/// ### Arguments
+	/// * `base_url` - The scheme and host to use instead (e.g. `http://127.0.0.1:8080`)
+	pub fn with_global_base_url<S: ToString>(mut self, base_url: S) -> Self {
+		self.base_urls.set_global(base_url.to_string());
+		self
+	}
+
+	/// Sets the policy used to retry rate limited and transiently failing
+	/// requests
+	///
+	/// ### Arguments
+	/// * `retry_policy` - The policy to use ([RetryPolicy::none] disables
+	///   retries)
+	pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+		self.retry_policy = retry_policy;
+		self
+	}
+
+	/// Adds a middleware after those already added
+	///
+	/// ### Arguments
+	/// * `middleware` - The middleware to run around every request
+	pub fn with_middleware<M: Middleware + 'static>(mut self, middleware: M) -> Self {
+		self.middleware.push(Arc::new(middleware));
+		self
+	}
+
+	/// Builds a request to an endpoint the crate does not wrap, sent with the
+	/// client's cookie, CSRF handling, retries and error mapping
+	///
+	/// ### Arguments
+	/// * `domain` - The API to send the request to, e.g. [RobloxApi::Custom]
+	///   for a host without a variant of its own
+	/// * `path` - The path of the endpoint, starting with `/`
+	pub fn request<S: ToString + Display>(&self, domain: RobloxApi, path: S) -> RequestBuilder<'_, State> {
+		RequestBuilder::new(domain, path, self)
 	}
 
 	async fn inner_request<U, T>(
 		&self,
+		domain: RobloxApi,
+		path: &str,
 		method: Method,
 		endpoint: String,
 		body: Option<U>,
@@ -51,50 +106,300 @@ impl<State> Robolt<State> {
 		T: DeserializeOwned,
 		U: Serialize,
 	{
-		let builder = {
-			let mut builder = self.http.request(method, format!("https://{endpoint}"));
-			let mut has_body = false;
+		let body = body
+			.map(|body| serde_json::to_vec(&body))
+			.transpose()
+			.map_err(|err| RoboltError::Validation(format!("Failed to serialize the request body: {err}")))?;
+
+		let cached = self.cache.as_ref().and_then(|cache| {
+			let (key, ttl) = cache.key(domain, path, &method, &endpoint, body.as_deref(), self.credential())?;
+			Some((cache, key, ttl))
+		});
+
+		if let Some((cache, key, _)) = &cached {
+			if let Some(body) = cache.get(key) {
+				return serde_json::from_slice::<T>(&body).map_err(|err| RoboltError::decode(err, &body));
+			}
+		}
+
+		let headers = HeaderMap::new();
+		let fetch = self.fetch(domain, &method, &endpoint, body.as_deref(), &headers);
+		let res = match InFlight::key(self.coalescing, &method, &endpoint, self.credential()) {
+			Some(key) => self.in_flight.run(key, fetch).await?,
+			None => fetch.await?,
+		};
+
+		let value = read_response(&res, || PendingRequest {
+			domain,
+			method,
+			endpoint,
+			body,
+		})?;
+
+		if let Some((cache, key, ttl)) = cached {
+			cache.insert(key, res.body, ttl);
+		}
+
+		Ok(value)
+	}
+
+	/// Sends a request again with additional headers, bypassing the cache
+	pub(crate) async fn resend<T>(&self, request: &PendingRequest, headers: &HeaderMap) -> Result<T, RoboltError>
+	where
+		T: DeserializeOwned,
+	{
+		let res = self
+			.fetch(
+				request.domain,
+				&request.method,
+				&request.endpoint,
+				request.body.as_deref(),
+				headers,
+			)
+			.await?;
+
+		read_response(&res, || request.clone())
+	}
+
+	/// Sends a request without a CSRF token and keeps the one Roblox hands out
+	/// when rejecting it, without replaying the request
+	pub(crate) async fn fetch_xcsrf(&self, domain: RobloxApi, path: &str) -> Result<(), RoboltError> {
+		let endpoint = format!("{}{path}", self.base_urls.resolve(&domain));
+		let req = self.build_request(domain, Method::POST, &endpoint, None, &HeaderMap::new());
 
-			if let Some(body) = &body {
-				builder = builder.json(body);
-				has_body = true;
+		if let Some(rate_limiter) = &self.rate_limiter {
+			rate_limiter.acquire(domain, self.credential()).await;
+		}
+
+		let res = self.dispatch(req).await?;
+
+		if let Some(xcsrf) = res.headers.get(XCSRF_HEADER).and_then(|v| v.to_str().ok()) {
+			self.set_xcsrf(xcsrf.to_string());
+		}
+
+		Ok(())
+	}
+
+	/// Sends a request until it succeeds or can no longer be retried,
+	/// refreshing the CSRF token along the way
+	async fn fetch(
+		&self,
+		domain: RobloxApi,
+		method: &Method,
+		endpoint: &str,
+		body: Option<&[u8]>,
+		headers: &HeaderMap,
+	) -> Result<HttpResponse, RoboltError> {
+		let mut refreshed_xcsrf = false;
+		#[cfg(feature = "oauth")]
+		let mut refreshed_token = false;
+		let mut attempt = 1;
+
+		loop {
+			#[cfg(feature = "oauth")]
+			if let Some(oauth) = self.oauth_for(domain) {
+				oauth.refresh(self, None).await?;
 			}
 
-			if let (Some(cookie), Some(xcsrf)) = (&self.cookie, &self.xcsrf) {
-				let mut headers = HeaderMap::new();
-				headers.insert(COOKIE, cookie.parse().unwrap());
-				headers.insert("x-csrf-token", xcsrf.parse().unwrap());
+			let req = self.build_request(domain, method.clone(), endpoint, body.map(<[u8]>::to_vec), headers);
+			#[cfg(feature = "oauth")]
+			let bearer = req
+				.header(AUTHORIZATION.as_str())
+				.and_then(|bearer| bearer.strip_prefix("Bearer "))
+				.map(str::to_string);
+
+			if let Some(rate_limiter) = &self.rate_limiter {
+				rate_limiter.acquire(domain, self.credential()).await;
+			}
 
-				if !has_body {
-					headers.insert(CONTENT_LENGTH, "0".parse().unwrap());
+			let res = match self.dispatch(req).await {
+				Ok(res) => res,
+				Err(err) if self.retry_policy.should_retry_error(method, &err, attempt) => {
+					sleep(self.retry_policy.delay(attempt, None)).await;
+					trace::record_retries(attempt);
+					attempt += 1;
+					continue;
+				},
+				Err(err) => return Err(err),
+			};
+
+			trace::record_status(res.status);
+
+			// Roblox rotates CSRF tokens and hands out a fresh one alongside the 403
+			// rejecting the stale one, so the request is replayed once with it
+			if res.status == StatusCode::FORBIDDEN && self.cookie.is_some() && !refreshed_xcsrf {
+				if let Some(xcsrf) = res.headers.get(XCSRF_HEADER).and_then(|v| v.to_str().ok()) {
+					self.set_xcsrf(xcsrf.to_string());
+					refreshed_xcsrf = true;
+					continue;
 				}
+			}
 
-				builder = builder.headers(headers);
+			// An access token can be revoked before it expires, in which case a
+			// new one is requested once
+			#[cfg(feature = "oauth")]
+			if let (Some(oauth), Some(bearer)) = (&self.oauth, &bearer) {
+				if res.status == StatusCode::UNAUTHORIZED && !refreshed_token {
+					oauth.refresh(self, Some(bearer)).await?;
+					refreshed_token = true;
+					continue;
+				}
 			}
 
-			builder
-		};
+			if self.retry_policy.should_retry_status(method, res.status, attempt) {
+				sleep(self.retry_policy.delay(attempt, Some(&res.headers))).await;
+				trace::record_retries(attempt);
+				attempt += 1;
+				continue;
+			}
 
-		let res = builder.send().await?;
-		let status = res.status();
+			return Ok(res);
+		}
+	}
 
-		if !status.is_success() {
-			let err_res = res.json::<RobloxAPIErrors>().await?;
+	/// Sends a request through the middleware chain and the transport
+	pub(crate) async fn dispatch(&self, mut req: HttpRequest) -> Result<HttpResponse, RoboltError> {
+		for middleware in self.middleware.iter() {
+			middleware.before_send(&mut req)?;
+		}
 
-			let err = err_res
-				.errors
-				.into_iter()
-				.next()
-				.ok_or(RoboltError::from(status.to_string()))?;
+		let start = Instant::now();
+		let mut res = self.transport.send(req.clone()).await;
+		let elapsed = start.elapsed();
 
-			return Err(err);
+		for middleware in self.middleware.iter().rev() {
+			middleware.after_receive(&req, &mut res, elapsed);
 		}
 
-		let json = res.json::<T>().await?;
-		Ok(json)
+		res
+	}
+
+	fn build_request(
+		&self,
+		domain: RobloxApi,
+		method: Method,
+		endpoint: &str,
+		body: Option<Vec<u8>>,
+		headers: &HeaderMap,
+	) -> HttpRequest {
+		let mut req = HttpRequest::new(method, endpoint);
+		req.headers = self.headers.clone();
+
+		for (name, value) in headers {
+			req.headers.insert(name, value.clone());
+		}
+
+		if body.is_some() {
+			req.headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
+		}
+
+		if let Some(cookie) = &self.cookie {
+			req.headers.insert(COOKIE, cookie.parse().unwrap());
+
+			if body.is_none() {
+				req.headers.insert(CONTENT_LENGTH, "0".parse().unwrap());
+			}
+		}
+
+		if let Some(xcsrf) = self.xcsrf() {
+			req.headers.insert(XCSRF_HEADER, xcsrf.parse().unwrap());
+		}
+
+		// The API key is only meant for Open Cloud, other hosts never see it
+		if let Some(api_key) = self.api_key.as_ref().filter(|_| domain == RobloxApi::OpenCloud) {
+			req.headers.insert(API_KEY_HEADER, api_key.parse().unwrap());
+		}
+
+		#[cfg(feature = "oauth")]
+		if let Some(oauth) = self.oauth_for(domain) {
+			let bearer = format!("Bearer {}", oauth.access_token());
+			req.headers.insert(AUTHORIZATION, bearer.parse().unwrap());
+		}
+
+		req.body = body;
+		req
+	}
+
+	/// The OAuth session whose access token is sent to the domain, which is
+	/// only the case for the OAuth and Open Cloud hosts
+	#[cfg(feature = "oauth")]
+	fn oauth_for(&self, domain: RobloxApi) -> Option<&OAuthSession> {
+		self.oauth
+			.as_deref()
+			.filter(|_| matches!(domain, RobloxApi::OAuth | RobloxApi::OpenCloud))
+	}
+
+	/// Moves the client configuration into a client of another state
+	pub(crate) fn into_state<S>(self, cookie: Option<String>, xcsrf: Option<String>) -> Robolt<S> {
+		Robolt {
+			state: PhantomData::<S>,
+			http: self.http,
+			transport: self.transport,
+			headers: self.headers,
+			middleware: self.middleware,
+			base_urls: self.base_urls,
+			retry_policy: self.retry_policy,
+			cache: self.cache,
+			rate_limiter: self.rate_limiter,
+			batch_concurrency: self.batch_concurrency,
+			coalescing: self.coalescing,
+			in_flight: self.in_flight,
+			cookie,
+			xcsrf: Arc::new(RwLock::new(xcsrf)),
+			session_info: None,
+			api_key: None,
+			#[cfg(feature = "oauth")]
+			oauth: None,
+		}
+	}
+
+	/// The cookie or API key the client is authenticated with, used to key
+	/// per-account state
+	fn credential(&self) -> Option<&str> {
+		#[cfg(feature = "oauth")]
+		if let Some(oauth) = &self.oauth {
+			return Some(&oauth.identity);
+		}
+
+		self.cookie.as_deref().or(self.api_key.as_deref())
+	}
+
+	pub(crate) fn xcsrf(&self) -> Option<String> {
+		self.xcsrf.read().unwrap().clone()
+	}
+
+	pub(crate) fn set_xcsrf(&self, xcsrf: String) {
+		*self.xcsrf.write().unwrap() = Some(xcsrf);
 	}
 }
 
+/// Reads a response into `T`, keeping the request in the error when Roblox
+/// asks for a challenge to be solved before it is resubmitted
+fn read_response<T: DeserializeOwned>(
+	res: &HttpResponse,
+	request: impl FnOnce() -> PendingRequest,
+) -> Result<T, RoboltError> {
+	if !res.status.is_success() {
+		return Err(match Challenge::from_response(res, request) {
+			Some(challenge) => RoboltError::Challenge(Box::new(challenge)),
+			None => RoboltError::from_response(res.status, &res.headers, &res.body),
+		});
+	}
+
+	// Some endpoints answer with an empty body, which is read as `null` so
+	// that it can be decoded into `()`
+	let body = if res.body.is_empty() {
+		b"null".as_slice()
+	} else {
+		&res.body
+	};
+
+	serde_json::from_slice::<T>(body).map_err(|err| RoboltError::decode(err, &res.body))
+}
+
+pub(crate) const XCSRF_HEADER: &str = "x-csrf-token";
+pub(crate) const API_KEY_HEADER: &str = "x-api-key";
+
 pub(crate) fn default_client_headers() -> HeaderMap {
 	let mut headers = HeaderMap::new();
 	headers.insert(CONTENT_TYPE, "application/json".parse().unwrap());
@@ -109,35 +414,93 @@ pub(crate) fn default_client_headers() -> HeaderMap {
 }
 
 impl<'a, State> RequestBuilder<'a, State> {
-	fn new<S: ToString + Display>(domain: &str, path: S, robolt: &'a Robolt<State>) -> Self {
+	fn new<S: ToString + Display>(domain: RobloxApi, path: S, robolt: &'a Robolt<State>) -> Self {
 		Self {
 			method: Method::GET,
-			endpoint: format!("{domain}{path}"),
+			endpoint: format!("{}{path}", robolt.base_urls.resolve(&domain)),
+			domain,
+			path: path.to_string(),
 			robolt,
 		}
 	}
 
-	pub(crate) fn method(mut self, method: Method) -> Self {
+	/// The HTTP method of the request (defaults to `GET`)
+	pub fn method(mut self, method: Method) -> Self {
 		self.method = method;
 		self
 	}
 
-	pub(crate) async fn send_body<T, U>(self, body: T) -> Result<U, RoboltError>
+	/// Appends a query parameter, percent-encoding the key and value
+	pub fn query<K: AsRef<str>, V: ToString>(mut self, key: K, value: V) -> Self {
+		let separator = if self.endpoint.contains('?') { '&' } else { '?' };
+		let key = byte_serialize(key.as_ref().as_bytes()).collect::<String>();
+		let value = byte_serialize(value.to_string().as_bytes()).collect::<String>();
+
+		self.endpoint = format!("{}{separator}{key}={value}", self.endpoint);
+		self
+	}
+
+	/// Sends the request with a JSON body and decodes the response into `U`,
+	/// which can be a [serde_json::Value]
+	pub async fn send_body<T, U>(self, body: T) -> Result<U, RoboltError>
 	where
 		T: Serialize,
 		U: DeserializeOwned,
 	{
-		self.robolt.inner_request(self.method, self.endpoint, Some(body)).await
+		self.execute(Some(body)).await
 	}
 
-	pub(crate) async fn send<T>(self) -> Result<T, RoboltError>
+	/// Sends the request and decodes the response into `T`, which can be a
+	/// [serde_json::Value]
+	pub async fn send<T>(self) -> Result<T, RoboltError>
 	where
 		T: DeserializeOwned,
+	{
+		self.execute::<(), T>(None).await
+	}
+
+	#[cfg(not(feature = "tracing"))]
+	async fn execute<U, T>(self, body: Option<U>) -> Result<T, RoboltError>
+	where
+		T: DeserializeOwned,
+		U: Serialize,
 	{
 		self.robolt
-			.inner_request::<(), T>(self.method, self.endpoint, None)
+			.inner_request(self.domain, &self.path, self.method, self.endpoint, body)
 			.await
 	}
+
+	/// Runs the request inside a span, which never records the URL or headers
+	/// so cookies and CSRF tokens cannot leak into traces
+	#[cfg(feature = "tracing")]
+	async fn execute<U, T>(self, body: Option<U>) -> Result<T, RoboltError>
+	where
+		T: DeserializeOwned,
+		U: Serialize,
+	{
+		use tracing::field::Empty;
+		use tracing::Instrument;
+
+		let span = tracing::info_span!(
+			"robolt.request",
+			domain = ?self.domain,
+			method = %self.method,
+			path = %trace::template_path(&self.path),
+			status = Empty,
+			retries = 0u32,
+			duration_ms = Empty,
+		);
+
+		let start = Instant::now();
+		let result = self
+			.robolt
+			.inner_request(self.domain, &self.path, self.method, self.endpoint, body)
+			.instrument(span.clone())
+			.await;
+
+		span.record("duration_ms", start.elapsed().as_millis() as u64);
+		result
+	}
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -146,6 +509,15 @@ pub struct Unauthenticated;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Authenticated;
 
+#[derive(Debug, Clone, PartialEq)]
+/// Authenticated with an Open Cloud API key rather than a cookie
+pub struct ApiKey;
+
+#[cfg(feature = "oauth")]
+#[derive(Debug, Clone, PartialEq)]
+/// Authenticated with OAuth 2.0 tokens on behalf of a user
+pub struct OAuth;
+
 #[derive(Debug, Clone)]
 pub struct Robolt<State = Unauthenticated> {
 	#[cfg(feature = "http")]
@@ -153,13 +525,37 @@ pub struct Robolt<State = Unauthenticated> {
 	#[cfg(not(feature = "http"))]
 	pub(crate) http: Client,
 	pub(crate) state: PhantomData<State>,
+	pub(crate) transport: Arc<dyn Transport>,
+	/// Sent with every request, including those of a custom transport
+	pub(crate) headers: HeaderMap,
+	pub(crate) middleware: Vec<Arc<dyn Middleware>>,
+	pub(crate) base_urls: BaseUrls,
+	pub(crate) retry_policy: RetryPolicy,
+	pub(crate) cache: Option<Arc<ResponseCache>>,
+	pub(crate) rate_limiter: Option<Arc<RateLimiter>>,
+	pub(crate) batch_concurrency: usize,
+	pub(crate) coalescing: Coalescing,
+	pub(crate) in_flight: Arc<InFlight>,
 	pub(crate) cookie: Option<String>,
-	pub(crate) xcsrf: Option<String>,
+	/// Shared between clones so that a refreshed token is picked up by all of
+	/// them
+	pub(crate) xcsrf: Arc<RwLock<Option<String>>>,
+	/// Set when logging in or restoring a session
+	pub(crate) session_info: Option<SessionInfo>,
+	pub(crate) api_key: Option<String>,
+	/// Shared between clones so that refreshed tokens are picked up by all of
+	/// them
+	#[cfg(feature = "oauth")]
+	pub(crate) oauth: Option<Arc<OAuthSession>>,
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct RequestBuilder<'a, State> {
+/// A request to any endpoint, created with [Robolt::request]
+pub struct RequestBuilder<'a, State> {
 	robolt: &'a Robolt<State>,
 	method: Method,
+	domain: RobloxApi,
+	/// The path without the base URL or query string
+	path: String,
 	endpoint: String,
-}
\ No newline at end of file
+}
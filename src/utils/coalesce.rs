@@ -0,0 +1,145 @@
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+
+use futures::channel::oneshot;
+use reqwest::Method;
+
+use crate::errors::RoboltError;
+use crate::transport::HttpResponse;
+use crate::Robolt;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// Which identical `GET` requests are merged while one of them is in flight
+///
+/// Merged requests share a single HTTP request, including its retries, and
+/// every caller receives a clone of its outcome. Responses served from the
+/// cache never reach this stage.
+pub enum Coalescing {
+	/// Every request is sent on its own
+	Disabled,
+	#[default]
+	/// Only requests sent without a cookie are merged
+	UnauthenticatedReads,
+	/// Requests sent with a cookie are merged too, but only with requests
+	/// sent with the same cookie
+	AllReads,
+}
+
+type Waiters = Vec<oneshot::Sender<Result<HttpResponse, RoboltError>>>;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct InFlightKey {
+	url: String,
+	identity: Option<Arc<str>>,
+}
+
+#[derive(Debug, Default)]
+/// The requests currently in flight, shared between clones of a client
+pub(crate) struct InFlight {
+	requests: Mutex<HashMap<InFlightKey, Waiters>>,
+}
+
+impl InFlight {
+	/// The key of a request, or `None` if it should not be merged
+	pub(crate) fn key(
+		coalescing: Coalescing,
+		method: &Method,
+		url: &str,
+		cookie: Option<&str>,
+	) -> Option<InFlightKey> {
+		let allowed = match coalescing {
+			Coalescing::Disabled => false,
+			Coalescing::UnauthenticatedReads => cookie.is_none(),
+			Coalescing::AllReads => true,
+		};
+
+		(allowed && *method == Method::GET).then(|| InFlightKey {
+			url: url.to_string(),
+			identity: cookie.map(Arc::from),
+		})
+	}
+
+	/// Awaits the request already in flight for the key if there is one, and
+	/// otherwise sends this one and hands its outcome to the requests that
+	/// joined it in the meantime
+	pub(crate) async fn run<F>(&self, key: InFlightKey, fetch: F) -> Result<HttpResponse, RoboltError>
+	where
+		F: Future<Output = Result<HttpResponse, RoboltError>>,
+	{
+		let waiter = {
+			let mut requests = self.requests.lock().unwrap();
+
+			match requests.get_mut(&key) {
+				Some(waiters) => {
+					let (sender, receiver) = oneshot::channel();
+					waiters.push(sender);
+					Some(receiver)
+				},
+				None => {
+					requests.insert(key.clone(), Vec::new());
+					None
+				},
+			}
+		};
+
+		if let Some(receiver) = waiter {
+			// The sender is only dropped without a value if the request in flight
+			// was cancelled, in which case this one is sent on its own
+			return match receiver.await {
+				Ok(res) => res,
+				Err(_) => fetch.await,
+			};
+		}
+
+		let leader = Leader {
+			in_flight: self,
+			key: Some(key),
+		};
+
+		let res = fetch.await;
+		leader.complete(&res);
+		res
+	}
+}
+
+/// Removes the entry of a request once it completes or is cancelled
+struct Leader<'a> {
+	in_flight: &'a InFlight,
+	/// Taken once the entry has been removed
+	key: Option<InFlightKey>,
+}
+
+impl Leader<'_> {
+	fn complete(mut self, res: &Result<HttpResponse, RoboltError>) {
+		let Some(key) = self.key.take() else {
+			return;
+		};
+
+		let waiters = self.in_flight.requests.lock().unwrap().remove(&key);
+
+		for waiter in waiters.into_iter().flatten() {
+			let _ = waiter.send(res.clone());
+		}
+	}
+}
+
+impl Drop for Leader<'_> {
+	fn drop(&mut self) {
+		if let (Some(key), Ok(mut requests)) = (self.key.take(), self.in_flight.requests.lock()) {
+			requests.remove(&key);
+		}
+	}
+}
+
+impl<State> Robolt<State> {
+	/// Sets which identical in-flight `GET` requests are merged into one
+	///
+	/// ### Arguments
+	/// * `coalescing` - The requests to merge (defaults to
+	///   [Coalescing::UnauthenticatedReads])
+	pub fn with_coalescing(mut self, coalescing: Coalescing) -> Self {
+		self.coalescing = coalescing;
+		self
+	}
+}
@@ -1,52 +1,301 @@
+use std::error::Error;
 use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
 
+use reqwest::header::HeaderMap;
+use reqwest::StatusCode;
 use serde::Deserialize;
 
-#[derive(Deserialize, Debug)]
+use crate::utils::challenge::Challenge;
+use crate::utils::retry::server_delay;
+
+/// The maximum number of characters of a response body kept in an error
+const BODY_EXCERPT_LENGTH: usize = 512;
+
+#[derive(Debug, Clone)]
+#[non_exhaustive]
 /// An error associated with either the Roblox API or a HTTP request.
-pub struct RoboltError {
-	pub message: String,
+pub enum RoboltError {
+	/// The request could not be sent or its response could not be read
+	Network(Arc<reqwest::Error>),
+	/// The API responded with an unsuccessful status and no Roblox error
+	Http {
+		status: StatusCode,
+		/// An excerpt of the response body
+		body: String,
+	},
+	/// The API responded with one or more Roblox errors
+	Api { status: StatusCode, errors: Vec<ApiError> },
+	/// The response body could not be deserialized into the expected type
+	Decode {
+		error: Arc<serde_json::Error>,
+		/// An excerpt of the response body
+		body: String,
+	},
+	/// The request requires a valid `.ROBLOSECURITY` cookie
+	Unauthorized { errors: Vec<ApiError> },
+	/// The request was rate limited
+	RateLimited {
+		/// How long the API asked to wait before retrying, if it said so
+		retry_after: Option<Duration>,
+		errors: Vec<ApiError>,
+	},
+	/// The input was rejected before a request was sent
+	Validation(String),
+	/// A custom [Transport](crate::transport::Transport) could not complete
+	/// the request
+	Transport(String),
+	/// A [Session](crate::session::Session) could not be restored, sealed or
+	/// opened
+	Session(String),
+	/// A `.ROBLOSECURITY` cookie was rejected while logging in
+	Login(LoginError),
+	/// The Open Cloud API key lacks a scope the request requires
+	InsufficientScope {
+		/// The message returned by Open Cloud
+		message: String,
+	},
+	/// Roblox requires a challenge to be solved before it accepts the
+	/// request, which can then be resubmitted with
+	/// [Robolt::resubmit](crate::Robolt::resubmit)
+	Challenge(Box<Challenge>),
+	/// The OAuth 2.0 authorization server rejected a token request
+	OAuth {
+		/// The error code, e.g. `invalid_grant`
+		error: String,
+		description: Option<String>,
+	},
+}
+
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+/// The reason a `.ROBLOSECURITY` cookie was rejected by
+/// [Robolt::login](crate::Robolt::login)
+pub enum LoginError {
+	/// The cookie is malformed and was rejected before a request was sent
+	Invalid,
+	/// The cookie is well-formed but no longer accepted by Roblox
+	Expired,
+	/// The account the cookie belongs to is locked or moderated
+	Locked { errors: Vec<ApiError> },
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// An error returned by the Roblox API
+pub struct ApiError {
 	#[serde(default = "default_error_code")]
-	pub code: i8,
-	#[serde(skip)]
-	kind: RoboltErrorKind,
+	pub code: i32,
+	#[serde(default)]
+	pub message: String,
+	pub user_facing_message: Option<String>,
+	pub field: Option<String>,
 }
 
-fn default_error_code() -> i8 {
+fn default_error_code() -> i32 {
 	-1
 }
 
+#[derive(Deserialize)]
+/// An error returned by Open Cloud, whose shape differs from other APIs
+struct OpenCloudError {
+	#[serde(alias = "code")]
+	error: String,
+	#[serde(default)]
+	message: String,
+}
+
 #[derive(Deserialize)]
 pub(crate) struct RobloxAPIErrors {
-	pub(crate) errors: Vec<RoboltError>,
+	#[serde(default)]
+	pub(crate) errors: Vec<ApiError>,
 }
 
-#[derive(Debug, Default)]
-enum RoboltErrorKind {
-	#[default]
-	Api,
-	Unknown,
+impl RoboltError {
+	/// The HTTP status of the response that caused the error, if any
+	pub fn status(&self) -> Option<StatusCode> {
+		match self {
+			RoboltError::Network(err) => err.status(),
+			RoboltError::Http {
+				status, ..
+			} |
+			RoboltError::Api {
+				status, ..
+			} => Some(*status),
+			RoboltError::Unauthorized {
+				..
+			} => Some(StatusCode::UNAUTHORIZED),
+			RoboltError::RateLimited {
+				..
+			} => Some(StatusCode::TOO_MANY_REQUESTS),
+			RoboltError::Login(LoginError::Expired) => Some(StatusCode::UNAUTHORIZED),
+			RoboltError::Challenge(challenge) => Some(challenge.status),
+			RoboltError::Login(LoginError::Locked {
+				..
+			}) |
+			RoboltError::InsufficientScope {
+				..
+			} => Some(StatusCode::FORBIDDEN),
+			RoboltError::Decode {
+				..
+			} |
+			RoboltError::Validation(_) |
+			RoboltError::Transport(_) |
+			RoboltError::Session(_) |
+			RoboltError::Login(_) |
+			RoboltError::OAuth {
+				..
+			} => None,
+		}
+	}
+
+	/// The Roblox errors returned by the API, if any
+	pub fn api_errors(&self) -> &[ApiError] {
+		match self {
+			RoboltError::Api {
+				errors, ..
+			} |
+			RoboltError::Unauthorized {
+				errors,
+			} |
+			RoboltError::RateLimited {
+				errors, ..
+			} |
+			RoboltError::Login(LoginError::Locked {
+				errors,
+			}) => errors,
+			_ => &[],
+		}
+	}
+
+	pub(crate) fn from_response(status: StatusCode, headers: &HeaderMap, body: &[u8]) -> Self {
+		if status == StatusCode::FORBIDDEN {
+			if let Ok(error) = serde_json::from_slice::<OpenCloudError>(body) {
+				if error.error == "INSUFFICIENT_SCOPE" {
+					return RoboltError::InsufficientScope {
+						message: error.message,
+					};
+				}
+			}
+		}
+
+		let errors = serde_json::from_slice::<RobloxAPIErrors>(body)
+			.map(|res| res.errors)
+			.unwrap_or_default();
+
+		match status {
+			StatusCode::UNAUTHORIZED => RoboltError::Unauthorized {
+				errors,
+			},
+			StatusCode::TOO_MANY_REQUESTS => RoboltError::RateLimited {
+				retry_after: server_delay(headers),
+				errors,
+			},
+			_ if errors.is_empty() => RoboltError::Http {
+				status,
+				body: excerpt(body),
+			},
+			_ => RoboltError::Api {
+				status,
+				errors,
+			},
+		}
+	}
+
+	pub(crate) fn decode(error: serde_json::Error, body: &[u8]) -> Self {
+		RoboltError::Decode {
+			error: Arc::new(error),
+			body: excerpt(body),
+		}
+	}
+}
+
+fn excerpt(body: &[u8]) -> String {
+	let body = String::from_utf8_lossy(body);
+
+	match body.char_indices().nth(BODY_EXCERPT_LENGTH) {
+		Some((end, _)) => format!("{}...", &body[..end]),
+		None => body.to_string(),
+	}
 }
 
-#[doc(hidden)]
 impl fmt::Display for RoboltError {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		let kind = match self.kind {
-			RoboltErrorKind::Api => format!("API Error (code: {})", self.code),
-			RoboltErrorKind::Unknown => "Unknown".to_string(),
-		};
+		write!(f, "[Robolt] ")?;
 
-		write!(f, "[Robolt] {}: {}", kind, self.message)
+		match self {
+			RoboltError::Network(err) => write!(f, "Network Error: {err}"),
+			RoboltError::Http {
+				status,
+				body,
+			} => write!(f, "HTTP Error ({status}): {body}"),
+			RoboltError::Api {
+				status,
+				errors,
+			} => write!(f, "API Error ({status}): {}", join(errors)),
+			RoboltError::Decode {
+				error,
+				body,
+			} => write!(f, "Decode Error: {error} in {body}"),
+			RoboltError::Unauthorized {
+				errors,
+			} => write!(f, "Unauthorized: {}", join(errors)),
+			RoboltError::RateLimited {
+				retry_after: Some(retry_after),
+				..
+			} => write!(f, "Rate Limited: retry after {}s", retry_after.as_secs_f64()),
+			RoboltError::RateLimited {
+				retry_after: None, ..
+			} => write!(f, "Rate Limited"),
+			RoboltError::Validation(message) => write!(f, "Validation Error: {message}"),
+			RoboltError::Transport(message) => write!(f, "Transport Error: {message}"),
+			RoboltError::Session(message) => write!(f, "Session Error: {message}"),
+			RoboltError::Login(LoginError::Invalid) => write!(f, "Login Error: the cookie is malformed"),
+			RoboltError::Login(LoginError::Expired) => write!(f, "Login Error: the cookie has expired"),
+			RoboltError::Login(LoginError::Locked {
+				errors,
+			}) => write!(f, "Login Error: the account is locked: {}", join(errors)),
+			RoboltError::InsufficientScope {
+				message,
+			} => write!(f, "Insufficient Scope: {message}"),
+			RoboltError::Challenge(challenge) => {
+				write!(f, "Challenge Required: {} ({})", challenge.kind, challenge.id)
+			},
+			RoboltError::OAuth {
+				error,
+				description: Some(description),
+			} => write!(f, "OAuth Error: {error} ({description})"),
+			RoboltError::OAuth {
+				error,
+				description: None,
+			} => write!(f, "OAuth Error: {error}"),
+		}
 	}
 }
 
-#[doc(hidden)]
-impl From<String> for RoboltError {
-	fn from(error: String) -> Self {
-		Self {
-			kind: RoboltErrorKind::Unknown,
-			code: default_error_code(),
-			message: error,
+impl fmt::Display for ApiError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "{} (code: {})", self.message, self.code)
+	}
+}
+
+fn join(errors: &[ApiError]) -> String {
+	errors
+		.iter()
+		.map(ApiError::to_string)
+		.collect::<Vec<String>>()
+		.join(", ")
+}
+
+impl Error for RoboltError {
+	fn source(&self) -> Option<&(dyn Error + 'static)> {
+		match self {
+			RoboltError::Network(err) => Some(err.as_ref()),
+			RoboltError::Decode {
+				error, ..
+			} => Some(error.as_ref()),
+			_ => None,
 		}
 	}
 }
@@ -54,10 +303,6 @@ impl From<String> for RoboltError {
 #[doc(hidden)]
 impl From<reqwest::Error> for RoboltError {
 	fn from(error: reqwest::Error) -> Self {
-		Self {
-			kind: RoboltErrorKind::Unknown,
-			code: default_error_code(),
-			message: error.to_string(),
-		}
+		RoboltError::Network(Arc::new(error))
 	}
 }
@@ -0,0 +1,36 @@
+use std::fmt::Debug;
+use std::time::Duration;
+
+use crate::errors::RoboltError;
+use crate::transport::{HttpRequest, HttpResponse};
+
+/// Hooks run around every request sent by [Robolt](crate::Robolt)
+///
+/// Middleware run in the order they were added before a request is sent,
+/// and in reverse order once it has been received, so the first middleware
+/// added wraps all the others. Every attempt goes through the chain,
+/// including CSRF refreshes and retries. A response replaced with an error
+/// is returned to the caller as is.
+pub trait Middleware: Debug + Send + Sync {
+	/// Inspects or modifies a request before it is handed to the transport
+	///
+	/// Returning an error aborts the request with that error.
+	fn before_send(&self, _request: &mut HttpRequest) -> Result<(), RoboltError> {
+		Ok(())
+	}
+
+	/// Inspects or modifies the outcome of a request
+	///
+	/// ### Arguments
+	/// * `request` - The request as it was sent
+	/// * `response` - The raw response, or the error returned by the transport;
+	///   either can be replaced
+	/// * `elapsed` - The time spent in the transport
+	fn after_receive(
+		&self,
+		_request: &HttpRequest,
+		_response: &mut Result<HttpResponse, RoboltError>,
+		_elapsed: Duration,
+	) {
+	}
+}
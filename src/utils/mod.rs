@@ -1,3 +1,19 @@
+pub mod batch;
+pub mod builder;
+pub mod cache;
+pub mod cassette;
+pub mod challenge;
 pub mod client;
+pub mod coalesce;
 pub mod errors;
+pub mod middleware;
+#[cfg(feature = "oauth")]
+#[doc(cfg(feature = "oauth"))]
+pub mod oauth;
+pub mod pagination;
+pub mod rate_limit;
 pub(crate) mod response;
+pub mod retry;
+pub mod session;
+pub(crate) mod trace;
+pub mod transport;
@@ -0,0 +1,449 @@
+use std::fmt;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use futures::lock::Mutex;
+use rand::RngCore;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::Method;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use url::form_urlencoded::Serializer;
+
+use crate::api::ids::UserId;
+use crate::api::routes::RobloxApi;
+use crate::errors::RoboltError;
+use crate::transport::HttpRequest;
+use crate::utils::client::{OAuth, Unauthenticated};
+use crate::Robolt;
+
+/// How long before it expires an access token is refreshed
+const REFRESH_MARGIN: Duration = Duration::from_secs(30);
+
+#[derive(Clone, PartialEq)]
+/// The credentials of an OAuth 2.0 application registered on the Creator
+/// Dashboard
+pub struct OAuthConfig {
+	pub client_id: String,
+	/// Left out for public clients, which rely on PKCE alone
+	pub client_secret: Option<String>,
+	/// Must match one of the redirect URIs of the application
+	pub redirect_uri: String,
+}
+
+impl fmt::Debug for OAuthConfig {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("OAuthConfig")
+			.field("client_id", &self.client_id)
+			.field("client_secret", &self.client_secret.as_ref().map(|_| "[REDACTED]"))
+			.field("redirect_uri", &self.redirect_uri)
+			.finish()
+	}
+}
+
+impl OAuthConfig {
+	pub fn new<S: ToString>(client_id: S, redirect_uri: S) -> Self {
+		Self {
+			client_id: client_id.to_string(),
+			client_secret: None,
+			redirect_uri: redirect_uri.to_string(),
+		}
+	}
+
+	pub fn client_secret<S: ToString>(mut self, client_secret: S) -> Self {
+		self.client_secret = Some(client_secret.to_string());
+		self
+	}
+}
+
+#[derive(Clone, PartialEq)]
+/// A PKCE code verifier and the challenge derived from it with SHA-256
+pub struct Pkce {
+	/// Kept until the authorization code is exchanged
+	pub verifier: String,
+	pub challenge: String,
+}
+
+impl Pkce {
+	/// Generates a random verifier
+	pub fn new() -> Self {
+		let mut bytes = [0; 32];
+		rand::thread_rng().fill_bytes(&mut bytes);
+
+		Self::from_verifier(URL_SAFE_NO_PAD.encode(bytes))
+	}
+
+	/// Derives the challenge of an existing verifier
+	pub fn from_verifier(verifier: String) -> Self {
+		let challenge = URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()));
+
+		Self {
+			verifier,
+			challenge,
+		}
+	}
+}
+
+impl Default for Pkce {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl fmt::Debug for Pkce {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Pkce")
+			.field("verifier", &"[REDACTED]")
+			.field("challenge", &self.challenge)
+			.finish()
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The URL to send the user to, along with what is needed to complete the
+/// flow once they are redirected back
+pub struct Authorization {
+	pub url: String,
+	/// Must be compared with the `state` query parameter of the redirect
+	pub state: String,
+	pub pkce: Pkce,
+}
+
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+/// The tokens issued by the authorization server
+pub struct OAuthTokens {
+	pub access_token: String,
+	pub refresh_token: Option<String>,
+	pub id_token: Option<String>,
+	/// The scopes granted, separated by spaces
+	pub scope: String,
+	/// When the access token expires
+	pub expires_at: SystemTime,
+}
+
+impl OAuthTokens {
+	fn is_expiring(&self) -> bool {
+		self.expires_at <= SystemTime::now() + REFRESH_MARGIN
+	}
+}
+
+impl fmt::Debug for OAuthTokens {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("OAuthTokens")
+			.field("access_token", &"[REDACTED]")
+			.field("refresh_token", &self.refresh_token.as_ref().map(|_| "[REDACTED]"))
+			.field("id_token", &self.id_token.as_ref().map(|_| "[REDACTED]"))
+			.field("scope", &self.scope)
+			.field("expires_at", &self.expires_at)
+			.finish()
+	}
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+	access_token: String,
+	refresh_token: Option<String>,
+	id_token: Option<String>,
+	#[serde(default)]
+	scope: String,
+	expires_in: u64,
+}
+
+impl From<TokenResponse> for OAuthTokens {
+	fn from(res: TokenResponse) -> Self {
+		Self {
+			access_token: res.access_token,
+			refresh_token: res.refresh_token,
+			id_token: res.id_token,
+			scope: res.scope,
+			expires_at: SystemTime::now() + Duration::from_secs(res.expires_in),
+		}
+	}
+}
+
+#[derive(Deserialize)]
+struct TokenError {
+	error: String,
+	error_description: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+/// The claims returned by the userinfo endpoint, depending on the scopes
+/// granted
+pub struct UserInfo {
+	/// The ID of the user, as a string
+	pub sub: String,
+	pub name: Option<String>,
+	pub nickname: Option<String>,
+	pub preferred_username: Option<String>,
+	/// When the account was created, in seconds since the Unix epoch
+	pub created_at: Option<u64>,
+	pub profile: Option<String>,
+	pub picture: Option<String>,
+}
+
+impl UserInfo {
+	pub fn user_id(&self) -> Option<UserId> {
+		self.sub.parse::<u64>().ok().map(UserId)
+	}
+}
+
+/// The tokens of an [OAuth] client, shared between its clones so that a
+/// refreshed token is picked up by all of them
+pub(crate) struct OAuthSession {
+	config: OAuthConfig,
+	tokens: RwLock<OAuthTokens>,
+	/// Held while refreshing so that concurrent requests refresh only once
+	refreshing: Mutex<()>,
+	/// Keys per-account state, unlike the access token it never changes
+	pub(crate) identity: String,
+}
+
+impl fmt::Debug for OAuthSession {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("OAuthSession")
+			.field("config", &self.config)
+			.field("tokens", &self.tokens)
+			.field("identity", &"[REDACTED]")
+			.finish()
+	}
+}
+
+impl OAuthSession {
+	pub(crate) fn access_token(&self) -> String {
+		self.tokens.read().unwrap().access_token.clone()
+	}
+
+	/// Refreshes the access token if it expires soon, or unconditionally if
+	/// it is still the `rejected` one
+	pub(crate) async fn refresh<S>(&self, robolt: &Robolt<S>, rejected: Option<&str>) -> Result<(), RoboltError> {
+		let stale = |tokens: &OAuthTokens| match rejected {
+			Some(rejected) => tokens.access_token == rejected,
+			None => tokens.is_expiring(),
+		};
+
+		if !stale(&self.tokens.read().unwrap()) {
+			return Ok(());
+		}
+
+		let _guard = self.refreshing.lock().await;
+		let refresh_token = {
+			let tokens = self.tokens.read().unwrap();
+
+			// Another request refreshed the token while this one was waiting
+			if !stale(&tokens) {
+				return Ok(());
+			}
+
+			tokens.refresh_token.clone()
+		};
+
+		let Some(refresh_token) = refresh_token else {
+			return Err(RoboltError::OAuth {
+				error: "invalid_grant".to_string(),
+				description: Some("The access token expired and no refresh token was issued".to_string()),
+			});
+		};
+
+		let mut tokens = robolt.refresh_token(&self.config, &refresh_token).await?;
+		tokens.refresh_token = tokens.refresh_token.or(Some(refresh_token));
+		*self.tokens.write().unwrap() = tokens;
+		Ok(())
+	}
+}
+
+impl<State> Robolt<State> {
+	/// Builds the URL of the consent page, with a random `state` and PKCE
+	/// challenge
+	///
+	/// ### Arguments
+	/// * `config` - The credentials of the application
+	/// * `scopes` - The scopes to request, e.g. `openid` and `profile`
+	pub fn authorization_url(&self, config: &OAuthConfig, scopes: &[&str]) -> Authorization {
+		let mut state = [0; 16];
+		rand::thread_rng().fill_bytes(&mut state);
+
+		let state = URL_SAFE_NO_PAD.encode(state);
+		let pkce = Pkce::new();
+		let query = Serializer::new(String::new())
+			.append_pair("client_id", &config.client_id)
+			.append_pair("redirect_uri", &config.redirect_uri)
+			.append_pair("scope", &scopes.join(" "))
+			.append_pair("response_type", "code")
+			.append_pair("state", &state)
+			.append_pair("code_challenge", &pkce.challenge)
+			.append_pair("code_challenge_method", "S256")
+			.finish();
+
+		Authorization {
+			url: format!(
+				"{}/oauth/v1/authorize?{query}",
+				self.base_urls.resolve(&RobloxApi::OAuth)
+			),
+			state,
+			pkce,
+		}
+	}
+
+	/// Exchanges the authorization code the user was redirected back with
+	///
+	/// ### Arguments
+	/// * `config` - The credentials of the application
+	/// * `code` - The `code` query parameter of the redirect
+	/// * `verifier` - The PKCE verifier of the [Authorization]
+	pub async fn exchange_code(
+		&self,
+		config: &OAuthConfig,
+		code: &str,
+		verifier: &str,
+	) -> Result<OAuthTokens, RoboltError> {
+		self.token_request(config, &[
+			("grant_type", "authorization_code"),
+			("code", code),
+			("code_verifier", verifier),
+			("redirect_uri", &config.redirect_uri),
+		])
+		.await
+	}
+
+	/// Issues a new access token, and usually a new refresh token
+	///
+	/// ### Arguments
+	/// * `config` - The credentials of the application
+	/// * `refresh_token` - The refresh token of the previous tokens
+	pub async fn refresh_token(
+		&self,
+		config: &OAuthConfig,
+		refresh_token: &str,
+	) -> Result<OAuthTokens, RoboltError> {
+		self.token_request(config, &[
+			("grant_type", "refresh_token"),
+			("refresh_token", refresh_token),
+		])
+		.await
+	}
+
+	/// Revokes a refresh token, along with the access tokens issued with it
+	///
+	/// ### Arguments
+	/// * `config` - The credentials of the application
+	/// * `token` - The refresh token to revoke
+	pub async fn revoke_token(&self, config: &OAuthConfig, token: &str) -> Result<(), RoboltError> {
+		self.form_request(config, "/oauth/v1/token/revoke", &[("token", token)])
+			.await
+			.map(|_| ())
+	}
+
+	async fn token_request(
+		&self,
+		config: &OAuthConfig,
+		params: &[(&str, &str)],
+	) -> Result<OAuthTokens, RoboltError> {
+		let body = self.form_request(config, "/oauth/v1/token", params).await?;
+
+		serde_json::from_slice::<TokenResponse>(&body)
+			.map(OAuthTokens::from)
+			.map_err(|err| RoboltError::decode(err, &body))
+	}
+
+	/// Sends a form to the authorization server, which neither takes JSON nor
+	/// the cookie or CSRF token of the client
+	async fn form_request(
+		&self,
+		config: &OAuthConfig,
+		path: &str,
+		params: &[(&str, &str)],
+	) -> Result<Vec<u8>, RoboltError> {
+		let mut req = HttpRequest::new(
+			Method::POST,
+			format!("{}{path}", self.base_urls.resolve(&RobloxApi::OAuth)),
+		);
+		req.headers = self.headers.clone();
+		req.headers
+			.insert(CONTENT_TYPE, "application/x-www-form-urlencoded".parse().unwrap());
+		req.body = Some(form(config, params));
+
+		let res = self.dispatch(req).await?;
+
+		if res.status.is_success() {
+			return Ok(res.body);
+		}
+
+		match serde_json::from_slice::<TokenError>(&res.body) {
+			Ok(err) => Err(RoboltError::OAuth {
+				error: err.error,
+				description: err.error_description,
+			}),
+			Err(_) => Err(RoboltError::from_response(res.status, &res.headers, &res.body)),
+		}
+	}
+}
+
+/// Encodes the parameters of a request to the authorization server, along
+/// with the credentials of the application
+fn form(config: &OAuthConfig, params: &[(&str, &str)]) -> Vec<u8> {
+	let mut form = Serializer::new(String::new());
+	form.extend_pairs(params).append_pair("client_id", &config.client_id);
+
+	if let Some(client_secret) = &config.client_secret {
+		form.append_pair("client_secret", client_secret);
+	}
+
+	form.finish().into_bytes()
+}
+
+impl Robolt<Unauthenticated> {
+	/// Authenticates the client with OAuth 2.0 tokens, sent as a bearer token
+	/// and refreshed when they expire or are rejected
+	///
+	/// ### Arguments
+	/// * `config` - The credentials of the application, used to refresh the
+	///   tokens
+	/// * `tokens` - Tokens issued by [Robolt::exchange_code]
+	pub fn set_oauth(self, config: OAuthConfig, tokens: OAuthTokens) -> Robolt<OAuth> {
+		let token = tokens.refresh_token.as_ref().unwrap_or(&tokens.access_token);
+		// A digest rather than the token itself, which would otherwise be copied
+		// into every cache and rate limit key
+		let identity = format!("oauth:{}", URL_SAFE_NO_PAD.encode(Sha256::digest(token.as_bytes())));
+		let mut client = self.into_state::<OAuth>(None, None);
+
+		client.oauth = Some(Arc::new(OAuthSession {
+			config,
+			tokens: RwLock::new(tokens),
+			refreshing: Mutex::new(()),
+			identity,
+		}));
+		client
+	}
+}
+
+impl Robolt<OAuth> {
+	pub fn remove_oauth(self) -> Robolt<Unauthenticated> {
+		self.into_state(None, None)
+	}
+
+	/// The current tokens, which change whenever they are refreshed
+	pub fn tokens(&self) -> OAuthTokens {
+		self.oauth_session().tokens.read().unwrap().clone()
+	}
+
+	/// Revokes the refresh token of the client
+	pub async fn revoke(self) -> Result<Robolt<Unauthenticated>, RoboltError> {
+		let tokens = self.tokens();
+		let token = tokens.refresh_token.as_ref().unwrap_or(&tokens.access_token);
+
+		self.revoke_token(&self.oauth_session().config, token).await?;
+		Ok(self.remove_oauth())
+	}
+
+	/// Fetches the claims of the user who granted the tokens
+	pub async fn userinfo(&self) -> Result<UserInfo, RoboltError> {
+		self.request(RobloxApi::OAuth, "/oauth/v1/userinfo").send().await
+	}
+
+	fn oauth_session(&self) -> &OAuthSession {
+		self.oauth.as_ref().expect("An OAuth client always has a session")
+	}
+}
@@ -0,0 +1,193 @@
+use std::future::{Future, IntoFuture};
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+use futures::stream::{self, Stream, TryStreamExt};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+
+use crate::api::routes::RobloxApi;
+use crate::api::Limit;
+use crate::errors::RoboltError;
+use crate::Robolt;
+
+/// The largest page size accepted by cursor-based endpoints
+const MAX_PAGE_SIZE: u32 = Limit::Max as u32;
+
+#[derive(Default, Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// A single page of results from a cursor-based endpoint
+pub struct Page<T> {
+	pub previous_page_cursor: Option<String>,
+	pub next_page_cursor: Option<String>,
+	pub data: Vec<T>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The order in which a cursor-based endpoint returns its items
+pub enum SortOrder {
+	Asc,
+	Desc,
+}
+
+impl SortOrder {
+	fn as_str(&self) -> &'static str {
+		match self {
+			SortOrder::Asc => "Asc",
+			SortOrder::Desc => "Desc",
+		}
+	}
+}
+
+/// Walks the pages of a cursor-based endpoint
+///
+/// Awaiting a [Paginator] directly returns the items of the first page. Use
+/// [Paginator::page] for page-by-page access with cursors, or
+/// [Paginator::into_stream] to walk every page.
+///
+/// ### Example
+///
+/// ```no_run
+/// use futures::TryStreamExt;
+/// use robolt::api::Limit;
+/// use robolt::pagination::SortOrder;
+/// use robolt::Robolt;
+///
+/// # async fn run() -> Result<(), robolt::errors::RoboltError> {
+/// let client = Robolt::new();
+/// let followers = client.followers(1, Limit::Max).sort_order(SortOrder::Desc);
+/// let users = followers.into_stream().try_collect::<Vec<_>>().await?;
+/// # Ok(())
+/// # }
+/// ```
+pub struct Paginator<'a, State, T> {
+	client: &'a Robolt<State>,
+	domain: RobloxApi,
+	path: String,
+	query: Vec<(&'static str, String)>,
+	limit: u32,
+	sort_order: Option<SortOrder>,
+	cursor: Option<String>,
+	item: PhantomData<fn() -> T>,
+}
+
+impl<'a, State, T> Paginator<'a, State, T>
+where
+	T: DeserializeOwned,
+{
+	pub(crate) fn new<S: ToString>(client: &'a Robolt<State>, domain: RobloxApi, path: S, limit: Limit) -> Self {
+		Self {
+			client,
+			domain,
+			path: path.to_string(),
+			query: Vec::new(),
+			limit: limit as u32,
+			sort_order: None,
+			cursor: None,
+			item: PhantomData,
+		}
+	}
+
+	pub(crate) fn query<S: ToString>(mut self, key: &'static str, value: S) -> Self {
+		self.query.push((key, value.to_string()));
+		self
+	}
+
+	/// The number of items requested per page
+	pub fn limit(mut self, limit: Limit) -> Self {
+		self.limit = limit as u32;
+		self
+	}
+
+	/// An arbitrary number of items requested per page, between 1 and 100
+	/// (checked when a page is fetched)
+	pub fn page_size(mut self, page_size: u32) -> Self {
+		self.limit = page_size;
+		self
+	}
+
+	pub fn sort_order(mut self, sort_order: SortOrder) -> Self {
+		self.sort_order = Some(sort_order);
+		self
+	}
+
+	/// Starts from the page identified by the cursor instead of the first one
+	pub fn cursor<S: ToString>(mut self, cursor: S) -> Self {
+		self.cursor = Some(cursor.to_string());
+		self
+	}
+
+	/// Fetches the page at the current cursor
+	pub async fn page(&self) -> Result<Page<T>, RoboltError> {
+		if !(1..=MAX_PAGE_SIZE).contains(&self.limit) {
+			return Err(RoboltError::Validation(format!(
+				"The page size must be between 1 and {MAX_PAGE_SIZE}, got {}",
+				self.limit
+			)));
+		}
+
+		let mut request = self.client.request(self.domain, &self.path);
+
+		for (key, value) in &self.query {
+			request = request.query(key, value);
+		}
+
+		request = request.query("limit", self.limit);
+
+		if let Some(sort_order) = self.sort_order {
+			request = request.query("sortOrder", sort_order.as_str());
+		}
+
+		if let Some(cursor) = &self.cursor {
+			request = request.query("cursor", cursor);
+		}
+
+		request.send().await
+	}
+
+	/// A stream of every page, starting from the current cursor
+	pub fn pages(self) -> impl Stream<Item = Result<Page<T>, RoboltError>> + 'a
+	where
+		State: 'a,
+		T: 'a,
+	{
+		stream::try_unfold(Some(self), |paginator| async move {
+			let Some(mut paginator) = paginator else {
+				return Ok(None);
+			};
+
+			let page = paginator.page().await?;
+			let next = page.next_page_cursor.clone().map(|cursor| {
+				paginator.cursor = Some(cursor);
+				paginator
+			});
+
+			Ok(Some((page, next)))
+		})
+	}
+
+	/// A stream of every item across all pages, starting from the current
+	/// cursor
+	pub fn into_stream(self) -> impl Stream<Item = Result<T, RoboltError>> + 'a
+	where
+		State: 'a,
+		T: 'a,
+	{
+		self.pages()
+			.map_ok(|page| stream::iter(page.data.into_iter().map(Ok)))
+			.try_flatten()
+	}
+}
+
+impl<'a, State, T> IntoFuture for Paginator<'a, State, T>
+where
+	State: Sync,
+	T: DeserializeOwned + Send + 'a,
+{
+	type IntoFuture = Pin<Box<dyn Future<Output = Self::Output> + Send + 'a>>;
+	type Output = Result<Vec<T>, RoboltError>;
+
+	fn into_future(self) -> Self::IntoFuture {
+		Box::pin(async move { self.page().await.map(|page| page.data) })
+	}
+}
@@ -0,0 +1,149 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::time::{sleep, Instant};
+
+use crate::api::routes::RobloxApi;
+use crate::Robolt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// A number of requests allowed per period, which is also the largest burst
+pub struct Rate {
+	requests: u32,
+	per: Duration,
+}
+
+impl Rate {
+	pub fn new(requests: u32, per: Duration) -> Self {
+		Self {
+			requests: requests.max(1),
+			per,
+		}
+	}
+
+	pub fn per_second(requests: u32) -> Self {
+		Self::new(requests, Duration::from_secs(1))
+	}
+
+	pub fn per_minute(requests: u32) -> Self {
+		Self::new(requests, Duration::from_secs(60))
+	}
+
+	fn refill_per_sec(&self) -> f64 {
+		self.requests as f64 / self.per.as_secs_f64().max(f64::EPSILON)
+	}
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+/// Throttles requests with a token bucket per [RobloxApi] domain
+///
+/// Domains without a rate of their own use the default rate, and are not
+/// throttled if there is none. Requests wait for a token before being sent,
+/// including retries.
+pub struct RateLimitPolicy {
+	default_rate: Option<Rate>,
+	domain_rates: HashMap<RobloxApi, Rate>,
+	per_cookie: bool,
+}
+
+impl RateLimitPolicy {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// The rate of domains without one of their own
+	pub fn default_rate(mut self, rate: Rate) -> Self {
+		self.default_rate = Some(rate);
+		self
+	}
+
+	/// The rate of a single domain
+	pub fn domain_rate(mut self, domain: RobloxApi, rate: Rate) -> Self {
+		self.domain_rates.insert(domain, rate);
+		self
+	}
+
+	/// Gives every cookie its own buckets, matching Roblox's per-account
+	/// throttling, instead of sharing them between all requests of the client
+	pub fn per_cookie(mut self, per_cookie: bool) -> Self {
+		self.per_cookie = per_cookie;
+		self
+	}
+
+	fn rate(&self, domain: RobloxApi) -> Option<Rate> {
+		self.domain_rates.get(&domain).or(self.default_rate.as_ref()).copied()
+	}
+}
+
+/// A domain and, when buckets are kept per cookie, the cookie
+type BucketKey = (RobloxApi, Option<Arc<str>>);
+
+#[derive(Debug)]
+/// The token buckets of a client, shared between its clones
+pub(crate) struct RateLimiter {
+	policy: RateLimitPolicy,
+	buckets: Mutex<HashMap<BucketKey, Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+	tokens: f64,
+	updated_at: Instant,
+}
+
+impl RateLimiter {
+	pub(crate) fn new(policy: RateLimitPolicy) -> Self {
+		Self {
+			policy,
+			buckets: Mutex::default(),
+		}
+	}
+
+	/// Waits until a request to the domain is allowed
+	pub(crate) async fn acquire(&self, domain: RobloxApi, cookie: Option<&str>) {
+		let Some(rate) = self.policy.rate(domain) else {
+			return;
+		};
+
+		let identity = cookie.filter(|_| self.policy.per_cookie).map(Arc::from);
+
+		loop {
+			let wait = {
+				let mut buckets = self.buckets.lock().unwrap();
+				let now = Instant::now();
+				let bucket = buckets.entry((domain, identity.clone())).or_insert(Bucket {
+					tokens: rate.requests as f64,
+					updated_at: now,
+				});
+
+				let elapsed = now.duration_since(bucket.updated_at).as_secs_f64();
+				bucket.tokens = (bucket.tokens + elapsed * rate.refill_per_sec()).min(rate.requests as f64);
+				bucket.updated_at = now;
+
+				if bucket.tokens >= 1.0 {
+					bucket.tokens -= 1.0;
+					return;
+				}
+
+				Duration::from_secs_f64((1.0 - bucket.tokens) / rate.refill_per_sec())
+			};
+
+			sleep(wait).await;
+		}
+	}
+}
+
+impl<State> Robolt<State> {
+	/// Throttles requests on the client side, replacing any existing limiter
+	///
+	/// The buckets are shared between clones of the client and kept when it
+	/// changes state.
+	///
+	/// ### Arguments
+	/// * `rate_limit_policy` - The rates to enforce per domain
+	pub fn with_rate_limit(mut self, rate_limit_policy: RateLimitPolicy) -> Self {
+		self.rate_limiter = Some(Arc::new(RateLimiter::new(rate_limit_policy)));
+		self
+	}
+}
@@ -0,0 +1,165 @@
+use std::time::{Duration, SystemTime};
+
+use rand::Rng;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Method, StatusCode};
+
+use crate::errors::RoboltError;
+
+const RATELIMIT_REMAINING_HEADER: &str = "x-ratelimit-remaining";
+const RATELIMIT_RESET_HEADER: &str = "x-ratelimit-reset";
+
+#[derive(Debug, Clone, PartialEq)]
+/// Controls how requests that failed with a rate limit, a transient server
+/// error or a network error are retried
+///
+/// ### Defaults
+///
+/// * **3** attempts in total
+/// * **500ms** base delay, doubled after every attempt
+/// * **30s** maximum delay
+/// * Jitter enabled
+/// * Non-idempotent requests are only retried after a 429
+pub struct RetryPolicy {
+	max_attempts: u32,
+	base_delay: Duration,
+	max_delay: Duration,
+	jitter: bool,
+	retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		Self {
+			max_attempts: 3,
+			base_delay: Duration::from_millis(500),
+			max_delay: Duration::from_secs(30),
+			jitter: true,
+			retry_non_idempotent: false,
+		}
+	}
+}
+
+impl RetryPolicy {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// A policy that never retries
+	pub fn none() -> Self {
+		Self::default().max_attempts(1)
+	}
+
+	/// The total number of attempts, including the first one
+	pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+		self.max_attempts = max_attempts.max(1);
+		self
+	}
+
+	/// The delay before the first retry, doubled after every attempt
+	pub fn base_delay(mut self, base_delay: Duration) -> Self {
+		self.base_delay = base_delay;
+		self
+	}
+
+	/// The upper bound of the exponential backoff
+	pub fn max_delay(mut self, max_delay: Duration) -> Self {
+		self.max_delay = max_delay;
+		self
+	}
+
+	/// Randomizes the backoff between zero and the computed delay
+	pub fn jitter(mut self, jitter: bool) -> Self {
+		self.jitter = jitter;
+		self
+	}
+
+	/// Also retries non-idempotent requests (e.g. `POST`) after a server or
+	/// network error, which may apply their side effects more than once
+	pub fn retry_non_idempotent(mut self, retry_non_idempotent: bool) -> Self {
+		self.retry_non_idempotent = retry_non_idempotent;
+		self
+	}
+
+	pub(crate) fn should_retry_status(&self, method: &Method, status: StatusCode, attempt: u32) -> bool {
+		if attempt >= self.max_attempts {
+			return false;
+		}
+
+		match status {
+			// A rate limited request was never processed, so it is always safe to replay
+			StatusCode::TOO_MANY_REQUESTS => true,
+			StatusCode::INTERNAL_SERVER_ERROR |
+			StatusCode::BAD_GATEWAY |
+			StatusCode::SERVICE_UNAVAILABLE |
+			StatusCode::GATEWAY_TIMEOUT => self.can_replay(method),
+			_ => false,
+		}
+	}
+
+	pub(crate) fn should_retry_error(&self, method: &Method, error: &RoboltError, attempt: u32) -> bool {
+		let transient = match error {
+			RoboltError::Network(err) => err.is_connect() || err.is_timeout(),
+			_ => false,
+		};
+
+		attempt < self.max_attempts && transient && self.can_replay(method)
+	}
+
+	/// The time to wait before the next attempt, preferring the server's
+	/// `Retry-After` and `x-ratelimit-*` hints over the backoff (both capped at
+	/// the maximum delay)
+	pub(crate) fn delay(&self, attempt: u32, headers: Option<&HeaderMap>) -> Duration {
+		headers
+			.and_then(server_delay)
+			.map(|delay| delay.min(self.max_delay))
+			.unwrap_or_else(|| self.backoff(attempt))
+	}
+
+	fn backoff(&self, attempt: u32) -> Duration {
+		let exponent = attempt.saturating_sub(1).min(31);
+		let delay = self.base_delay.saturating_mul(1 << exponent).min(self.max_delay);
+
+		if self.jitter && !delay.is_zero() {
+			rand::thread_rng().gen_range(Duration::ZERO..=delay)
+		} else {
+			delay
+		}
+	}
+
+	fn can_replay(&self, method: &Method) -> bool {
+		self.retry_non_idempotent || is_idempotent(method)
+	}
+}
+
+fn is_idempotent(method: &Method) -> bool {
+	matches!(
+		*method,
+		Method::GET | Method::HEAD | Method::OPTIONS | Method::TRACE | Method::PUT | Method::DELETE
+	)
+}
+
+/// The delay requested by the server through `Retry-After` (in seconds or as
+/// an HTTP date) or the `x-ratelimit-*` headers
+pub(crate) fn server_delay(headers: &HeaderMap) -> Option<Duration> {
+	let header = |name| headers.get(name).and_then(|v| v.to_str().ok()).map(str::trim);
+
+	if let Some(retry_after) = header(RETRY_AFTER.as_str()) {
+		if let Ok(seconds) = retry_after.parse::<u64>() {
+			return Some(Duration::from_secs(seconds));
+		}
+
+		if let Ok(date) = httpdate::parse_http_date(retry_after) {
+			// A date in the past means the request can be retried right away
+			return Some(date.duration_since(SystemTime::now()).unwrap_or_default());
+		}
+	}
+
+	if header(RATELIMIT_REMAINING_HEADER) == Some("0") {
+		return header(RATELIMIT_RESET_HEADER)
+			.and_then(|v| v.parse::<f64>().ok())
+			.and_then(|seconds| Duration::try_from_secs_f64(seconds).ok());
+	}
+
+	None
+}
@@ -0,0 +1,165 @@
+use std::fmt;
+use std::time::SystemTime;
+
+use reqwest::header::HeaderValue;
+use serde::{Deserialize, Serialize};
+
+use crate::api::ids::UserId;
+use crate::errors::RoboltError;
+use crate::utils::client::{Authenticated, Unauthenticated};
+use crate::Robolt;
+
+/// The prefix of the cookie header sent by an authenticated client
+const COOKIE_PREFIX: &str = ".ROBLOSECURITY=";
+
+#[derive(Clone, PartialEq, Deserialize, Serialize)]
+/// The credentials of an authenticated client, which can be stored and
+/// restored later without sending any request
+///
+/// With the `session-encryption` feature, a session can also be sealed with
+/// a passphrase before it is stored.
+pub struct Session {
+	/// The `.ROBLOSECURITY` cookie
+	pub cookie: String,
+	/// The CSRF token in use when the session was exported
+	pub xcsrf: Option<String>,
+	/// The ID of the authenticated user
+	pub user_id: UserId,
+	/// When the client logged in, kept across exports and restores
+	pub created_at: SystemTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The parts of a [Session] recorded by an authenticated client
+pub(crate) struct SessionInfo {
+	pub(crate) user_id: UserId,
+	pub(crate) created_at: SystemTime,
+}
+
+impl fmt::Debug for Session {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Session")
+			.field("cookie", &"[REDACTED]")
+			.field("xcsrf", &self.xcsrf.as_ref().map(|_| "[REDACTED]"))
+			.field("user_id", &self.user_id)
+			.field("created_at", &self.created_at)
+			.finish()
+	}
+}
+
+impl Robolt<Authenticated> {
+	/// Exports the credentials of the client without sending any request
+	pub fn session(&self) -> Result<Session, RoboltError> {
+		let info = self
+			.session_info
+			.ok_or_else(|| RoboltError::Session("The client was not logged in".to_string()))?;
+		let cookie = self.cookie.as_deref().unwrap_or_default();
+
+		Ok(Session {
+			cookie: cookie.trim_start_matches(COOKIE_PREFIX).to_string(),
+			xcsrf: self.xcsrf(),
+			user_id: info.user_id,
+			created_at: info.created_at,
+		})
+	}
+}
+
+impl Robolt<Unauthenticated> {
+	/// Authenticates the client with an exported session without sending any
+	/// request
+	///
+	/// The session is revalidated lazily: a stale CSRF token is replaced on
+	/// the first request it is rejected for, while an expired cookie surfaces
+	/// as [RoboltError::Unauthorized].
+	///
+	/// ### Arguments
+	/// * `session` - A session exported with [Robolt::session]
+	pub fn restore(self, session: Session) -> Result<Robolt<Authenticated>, RoboltError> {
+		let cookie = format!("{COOKIE_PREFIX}{}", session.cookie);
+
+		if HeaderValue::from_str(&cookie).is_err() ||
+			session
+				.xcsrf
+				.as_deref()
+				.is_some_and(|xcsrf| HeaderValue::from_str(xcsrf).is_err())
+		{
+			return Err(RoboltError::Session(
+				"The session contains characters that are not allowed in a header".to_string(),
+			));
+		}
+
+		let mut client = self.into_state::<Authenticated>(Some(cookie), session.xcsrf);
+		client.session_info = Some(SessionInfo {
+			user_id: session.user_id,
+			created_at: session.created_at,
+		});
+
+		Ok(client)
+	}
+}
+
+#[cfg(feature = "session-encryption")]
+mod encryption {
+	use aes_gcm::aead::{Aead, KeyInit};
+	use aes_gcm::{Aes256Gcm, Key, Nonce};
+	use argon2::Argon2;
+	use base64::engine::general_purpose::STANDARD;
+	use base64::Engine;
+	use rand::RngCore;
+
+	use super::Session;
+	use crate::errors::RoboltError;
+
+	const SALT_LENGTH: usize = 16;
+	const NONCE_LENGTH: usize = 12;
+
+	#[doc(cfg(feature = "session-encryption"))]
+	impl Session {
+		/// Encrypts the session with AES-256-GCM and a key derived from the
+		/// passphrase with Argon2, encoded as base64
+		pub fn seal(&self, passphrase: &str) -> Result<String, RoboltError> {
+			let mut salt = [0; SALT_LENGTH];
+			let mut nonce = [0; NONCE_LENGTH];
+			rand::thread_rng().fill_bytes(&mut salt);
+			rand::thread_rng().fill_bytes(&mut nonce);
+
+			let session = serde_json::to_vec(self).map_err(|err| RoboltError::Session(err.to_string()))?;
+			let ciphertext = cipher(passphrase, &salt)?
+				.encrypt(Nonce::from_slice(&nonce), session.as_slice())
+				.map_err(|_| RoboltError::Session("The session could not be encrypted".to_string()))?;
+
+			Ok(STANDARD.encode([&salt[..], &nonce, &ciphertext].concat()))
+		}
+
+		/// Decrypts a session sealed with [Session::seal]
+		pub fn open(sealed: &str, passphrase: &str) -> Result<Session, RoboltError> {
+			let sealed = STANDARD
+				.decode(sealed)
+				.map_err(|_| RoboltError::Session("The sealed session is not valid base64".to_string()))?;
+
+			if sealed.len() < SALT_LENGTH + NONCE_LENGTH {
+				return Err(RoboltError::Session("The sealed session is truncated".to_string()));
+			}
+
+			let (salt, rest) = sealed.split_at(SALT_LENGTH);
+			let (nonce, ciphertext) = rest.split_at(NONCE_LENGTH);
+			let session = cipher(passphrase, salt)?
+				.decrypt(Nonce::from_slice(nonce), ciphertext)
+				.map_err(|_| {
+					RoboltError::Session("The passphrase is wrong or the session was tampered with".to_string())
+				})?;
+
+			serde_json::from_slice(&session).map_err(|err| RoboltError::Session(err.to_string()))
+		}
+	}
+
+	fn cipher(passphrase: &str, salt: &[u8]) -> Result<Aes256Gcm, RoboltError> {
+		let mut key = Key::<Aes256Gcm>::default();
+
+		Argon2::default()
+			.hash_password_into(passphrase.as_bytes(), salt, &mut key)
+			.map_err(|err| RoboltError::Session(err.to_string()))?;
+
+		Ok(Aes256Gcm::new(&key))
+	}
+}
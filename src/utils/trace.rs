@@ -0,0 +1,37 @@
+//! Span bookkeeping for the `tracing` feature, compiled to no-ops without it
+
+use reqwest::StatusCode;
+
+/// Replaces the numeric segments of a path with `{id}` so requests to the same
+/// endpoint can be grouped regardless of the IDs they were called with
+pub(crate) fn template_path(path: &str) -> String {
+	path.split('?')
+		.next()
+		.unwrap_or_default()
+		.split('/')
+		.map(|segment| {
+			if !segment.is_empty() && segment.bytes().all(|b| b.is_ascii_digit()) {
+				"{id}"
+			} else {
+				segment
+			}
+		})
+		.collect::<Vec<&str>>()
+		.join("/")
+}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn record_status(status: StatusCode) {
+	tracing::Span::current().record("status", status.as_u16());
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn record_status(_status: StatusCode) {}
+
+#[cfg(feature = "tracing")]
+pub(crate) fn record_retries(retries: u32) {
+	tracing::Span::current().record("retries", retries);
+}
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) fn record_retries(_retries: u32) {}
@@ -0,0 +1,192 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use futures::future::BoxFuture;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Client, Method, StatusCode};
+use serde::de::DeserializeOwned;
+
+use crate::errors::RoboltError;
+
+/// Sends the requests built by [Robolt](crate::Robolt)
+///
+/// [ReqwestTransport] is used unless another transport is configured through
+/// [RoboltBuilder::transport](crate::RoboltBuilder::transport).
+pub trait Transport: Debug + Send + Sync {
+	fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, RoboltError>>;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A request as it is handed to a [Transport]
+pub struct HttpRequest {
+	pub method: Method,
+	pub url: String,
+	pub headers: HeaderMap,
+	pub body: Option<Vec<u8>>,
+}
+
+impl HttpRequest {
+	pub fn new<S: ToString>(method: Method, url: S) -> Self {
+		Self {
+			method,
+			url: url.to_string(),
+			headers: HeaderMap::new(),
+			body: None,
+		}
+	}
+
+	/// The value of a header, if it is present and valid UTF-8
+	pub fn header(&self, name: &str) -> Option<&str> {
+		self.headers.get(name).and_then(|v| v.to_str().ok())
+	}
+
+	/// Deserializes the JSON body, if there is one
+	pub fn json<T: DeserializeOwned>(&self) -> Option<Result<T, serde_json::Error>> {
+		self.body.as_deref().map(serde_json::from_slice)
+	}
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A response as it is returned by a [Transport]
+pub struct HttpResponse {
+	pub status: StatusCode,
+	pub headers: HeaderMap,
+	pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+	pub fn new<B: Into<Vec<u8>>>(status: StatusCode, body: B) -> Self {
+		Self {
+			status,
+			headers: HeaderMap::new(),
+			body: body.into(),
+		}
+	}
+
+	/// Adds a header to the response
+	///
+	/// # Panics
+	///
+	/// Panics if the name or value is not a valid header
+	pub fn header(mut self, name: &str, value: &str) -> Self {
+		self.headers.append(
+			HeaderName::try_from(name).expect("Invalid header name"),
+			HeaderValue::try_from(value).expect("Invalid header value"),
+		);
+		self
+	}
+}
+
+#[derive(Debug, Clone)]
+/// Sends requests over the network through [reqwest]
+pub struct ReqwestTransport {
+	client: Client,
+}
+
+impl ReqwestTransport {
+	pub fn new(client: Client) -> Self {
+		Self {
+			client,
+		}
+	}
+}
+
+impl Transport for ReqwestTransport {
+	fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, RoboltError>> {
+		Box::pin(async move {
+			let mut builder = self
+				.client
+				.request(request.method, request.url)
+				.headers(request.headers);
+
+			if let Some(body) = request.body {
+				builder = builder.body(body);
+			}
+
+			let res = builder.send().await?;
+
+			Ok(HttpResponse {
+				status: res.status(),
+				headers: res.headers().clone(),
+				body: res.bytes().await?.to_vec(),
+			})
+		})
+	}
+}
+
+#[derive(Debug, Clone, Default)]
+/// Serves canned responses from memory and records every request it receives
+///
+/// Responses are registered per method and URL (including the query string)
+/// and served in the order they were registered. The last response
+/// registered for a route keeps being served once the others are used up.
+/// Requests without a registered response fail with
+/// [RoboltError::Transport].
+///
+/// Clones share their responses and recorded requests, so a clone can be
+/// handed to [RoboltBuilder::transport](crate::RoboltBuilder::transport)
+/// while the original is kept for assertions.
+pub struct MockTransport {
+	state: Arc<Mutex<MockState>>,
+}
+
+#[derive(Debug, Default)]
+struct MockState {
+	responses: HashMap<(Method, String), VecDeque<HttpResponse>>,
+	requests: Vec<HttpRequest>,
+}
+
+impl MockTransport {
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Registers a JSON response for the given method and URL
+	pub fn respond<U: ToString, B: Into<Vec<u8>>>(&self, method: Method, url: U, status: u16, body: B) -> &Self {
+		let status = StatusCode::from_u16(status).expect("Invalid status code");
+		self.respond_with(method, url, HttpResponse::new(status, body))
+	}
+
+	/// Registers a response for the given method and URL
+	pub fn respond_with<U: ToString>(&self, method: Method, url: U, response: HttpResponse) -> &Self {
+		self.state
+			.lock()
+			.unwrap()
+			.responses
+			.entry((method, url.to_string()))
+			.or_default()
+			.push_back(response);
+
+		self
+	}
+
+	/// Every request received so far, in the order they were sent
+	pub fn requests(&self) -> Vec<HttpRequest> {
+		self.state.lock().unwrap().requests.clone()
+	}
+
+	/// The most recent request received, if any
+	pub fn last_request(&self) -> Option<HttpRequest> {
+		self.state.lock().unwrap().requests.last().cloned()
+	}
+}
+
+impl Transport for MockTransport {
+	fn send(&self, request: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, RoboltError>> {
+		let mut state = self.state.lock().unwrap();
+		let key = (request.method.clone(), request.url.clone());
+		state.requests.push(request);
+
+		let response = match state.responses.get_mut(&key) {
+			Some(responses) if responses.len() > 1 => responses.pop_front(),
+			Some(responses) => responses.front().cloned(),
+			None => None,
+		};
+
+		let response =
+			response.ok_or_else(|| RoboltError::Transport(format!("No mock response for {} {}", key.0, key.1)));
+
+		Box::pin(async move { response })
+	}
+}
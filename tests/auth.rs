@@ -1,7 +1,125 @@
+use reqwest::StatusCode;
+use tokio_test::assert_ok;
+
+use common::{xcsrf_mock_response, MockResponse, MockServer, AUTHENTICATED_USER};
+use robolt::api::routes::RobloxApi;
+use robolt::errors::{LoginError, RoboltError};
 use robolt::Robolt;
 
+mod common;
+
 #[test]
 fn is_authenticated() {
 	let client = Robolt::new();
 	assert!(!client.is_authenticated());
 }
+
+#[tokio::test]
+async fn xcsrf_refresh() {
+	let server = MockServer::start(vec![
+		MockResponse::json(200, AUTHENTICATED_USER),
+		MockResponse::json(403, r#"{"errors":[]}"#).header("x-csrf-token", "initial"),
+		MockResponse::json(200, "{}"),
+		MockResponse::json(403, r#"{"errors":[{"code":0,"message":"Token Validation Failed"}]}"#)
+			.header("x-csrf-token", "rotated"),
+		MockResponse::json(200, "{}"),
+	]);
+
+	let client = Robolt::new()
+		.with_global_base_url(&server.url)
+		.set_cookie("cookie".to_string())
+		.await
+		.unwrap();
+	let clone = client.clone();
+
+	assert_ok!(client.unfriend(1).await);
+	assert_ok!(clone.unfollow(1).await);
+
+	let requests = server.requests();
+	assert_eq!(requests[1].path, "/v1/usernames/validate");
+	assert_eq!(requests[1].header("x-csrf-token"), None);
+	assert_eq!(requests[2].header("x-csrf-token"), Some("initial"));
+	assert_eq!(requests[3].header("x-csrf-token"), Some("initial"));
+	assert_eq!(requests[4].header("x-csrf-token"), Some("rotated"));
+	assert_eq!(requests[4].path, "/v1/users/1/unfollow");
+}
+
+#[tokio::test]
+async fn remove_cookie() {
+	let server = MockServer::start(vec![MockResponse::json(200, AUTHENTICATED_USER), xcsrf_mock_response()]);
+	let client = Robolt::new()
+		.with_base_url(RobloxApi::Users, &server.url)
+		.with_base_url(RobloxApi::Auth, &server.url)
+		.set_cookie("cookie".to_string())
+		.await
+		.unwrap();
+
+	assert!(client.is_authenticated());
+	assert!(!client.remove_cookie().is_authenticated());
+}
+
+#[tokio::test]
+async fn login() {
+	let server = MockServer::start(vec![
+		MockResponse::json(200, AUTHENTICATED_USER),
+		xcsrf_mock_response(),
+		MockResponse::json(200, "{}"),
+	]);
+	let (client, user) = Robolt::new()
+		.with_global_base_url(&server.url)
+		.login("cookie".to_string())
+		.await
+		.unwrap();
+
+	assert!(client.is_authenticated());
+	assert_eq!(user.id, 1);
+
+	// The CSRF token obtained while logging in is sent with the first
+	// mutating request, which is not rejected for lacking one
+	assert_ok!(client.unfriend(1).await);
+
+	let requests = server.requests();
+	assert_eq!(requests.len(), 3);
+	assert_eq!(requests[0].method, "GET");
+	assert_eq!(requests[0].path, "/v1/users/authenticated");
+	assert_eq!(requests[0].header("x-csrf-token"), None);
+	assert_eq!(requests[1].method, "POST");
+	assert_eq!(requests[1].path, "/v1/usernames/validate");
+	assert_eq!(requests[2].header("x-csrf-token"), Some("token"));
+}
+
+#[tokio::test]
+async fn login_errors() {
+	let server = MockServer::start(vec![
+		MockResponse::json(
+			401,
+			r#"{"errors":[{"code":0,"message":"Authorization has been denied for this request."}]}"#,
+		),
+		MockResponse::json(403, r#"{"errors":[{"code":0,"message":"Account is locked"}]}"#),
+		MockResponse::json(403, r#"{"errors":[{"code":0,"message":"Token Validation Failed"}]}"#),
+	]);
+	let login = |cookie: &str| {
+		Robolt::new()
+			.with_global_base_url(&server.url)
+			.login(cookie.to_string())
+	};
+
+	let err = login("").await.unwrap_err();
+	assert!(matches!(err, RoboltError::Login(LoginError::Invalid)));
+	let err = login("line\nbreak").await.unwrap_err();
+	assert!(matches!(err, RoboltError::Login(LoginError::Invalid)));
+	assert!(server.requests().is_empty());
+
+	let err = login("expired").await.unwrap_err();
+	assert!(matches!(err, RoboltError::Login(LoginError::Expired)));
+	assert_eq!(err.status(), Some(StatusCode::UNAUTHORIZED));
+
+	let err = login("locked").await.unwrap_err();
+	assert!(matches!(err, RoboltError::Login(LoginError::Locked { .. })));
+	assert_eq!(err.api_errors()[0].message, "Account is locked");
+
+	// Other rejections are not mistaken for a locked account
+	let err = login("forbidden").await.unwrap_err();
+	assert!(matches!(err, RoboltError::Api { .. }));
+	assert_eq!(err.status(), Some(StatusCode::FORBIDDEN));
+}
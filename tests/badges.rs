@@ -26,5 +26,5 @@ async fn awarded_badge_timestamps() {
 	let client = Robolt::new();
 	let badges = vec![276292005, 275629737, 276292089];
 
-	assert_ok!(client.awarded_badge_timestamps(2, badges).await);
+	assert_ok!(client.awarded_badge_timestamps(2, badges).await.into_result());
 }
@@ -0,0 +1,61 @@
+use reqwest::Method;
+
+use robolt::api::ids::UserId;
+use robolt::errors::RoboltError;
+use robolt::transport::MockTransport;
+use robolt::{RetryPolicy, Robolt};
+
+fn awarded_dates_url(badge_ids: impl Iterator<Item = u64>) -> String {
+	let badge_ids = badge_ids.map(|id| id.to_string()).collect::<Vec<String>>();
+	format!(
+		"https://badges.roblox.com/v1/users/1/badges/awarded-dates?badgeIds={}",
+		badge_ids.join(",")
+	)
+}
+
+fn awarded_dates(badge_ids: &[u64]) -> String {
+	let data = badge_ids
+		.iter()
+		.map(|id| format!(r#"{{"badgeId":{id},"awardedDate":"2023-01-01T00:00:00Z"}}"#))
+		.collect::<Vec<String>>();
+
+	format!(r#"{{"data":[{}]}}"#, data.join(","))
+}
+
+#[tokio::test]
+async fn chunked_lookup() {
+	let transport = MockTransport::new();
+	transport.respond(Method::GET, awarded_dates_url(1..=100), 200, awarded_dates(&[1, 100]));
+	transport.respond(Method::GET, awarded_dates_url(101..=200), 200, awarded_dates(&[101]));
+	transport.respond(Method::GET, awarded_dates_url(201..=250), 400, "{}");
+
+	let client = Robolt::builder()
+		.transport(transport.clone())
+		.retry_policy(RetryPolicy::none())
+		.batch_concurrency(2)
+		.build()
+		.unwrap();
+	let res = client.awarded_badge_timestamps(1, (1..=250).collect()).await;
+
+	let ids = res.items.iter().map(|badge| badge.badge_id.get()).collect::<Vec<u64>>();
+	assert_eq!(ids, [1, 100, 101]);
+	assert_eq!(transport.requests().len(), 3);
+
+	assert!(!res.is_complete());
+	assert_eq!(
+		res.failed_inputs().copied().map(u64::from).collect::<Vec<u64>>(),
+		(201..=250).collect::<Vec<u64>>()
+	);
+	assert!(matches!(res.into_result(), Err(RoboltError::Http { .. })));
+}
+
+#[tokio::test]
+async fn empty_lookup() {
+	let transport = MockTransport::new();
+	let client = Robolt::builder().transport(transport.clone()).build().unwrap();
+	let res = client.presences(Vec::<UserId>::new()).await;
+
+	assert!(res.is_complete());
+	assert!(res.items.is_empty());
+	assert!(transport.requests().is_empty());
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "blocking")]
+
+use common::{xcsrf_mock_response, MockResponse, MockServer, AUTHENTICATED_USER};
+use robolt::api::Limit;
+use robolt::blocking::Robolt;
+
+mod common;
+
+#[test]
+fn user() {
+	let server = MockServer::start(vec![MockResponse::json(
+		200,
+		r#"{"description":"","created":"2006-02-27T21:06:40.3Z","isBanned":false,"externalAppDisplayName":null,"hasVerifiedBadge":true,"id":1,"name":"Roblox","displayName":"Roblox"}"#,
+	)]);
+	let client = Robolt::from_async(robolt::Robolt::new().with_global_base_url(&server.url));
+
+	assert_eq!(client.user(1).unwrap().username, "Roblox");
+}
+
+#[test]
+fn set_cookie() {
+	let server = MockServer::start(vec![
+		MockResponse::json(200, AUTHENTICATED_USER),
+		xcsrf_mock_response(),
+		MockResponse::json(200, "{}"),
+	]);
+	let client = Robolt::from_async(robolt::Robolt::new().with_global_base_url(&server.url))
+		.set_cookie("cookie".to_string())
+		.unwrap();
+
+	assert!(client.is_authenticated());
+	assert!(client.unfriend(1).is_ok());
+	assert_eq!(server.requests()[2].header("x-csrf-token"), Some("token"));
+}
+
+#[test]
+fn paginator() {
+	let server = MockServer::start(vec![
+		MockResponse::json(
+			200,
+			r#"{"nextPageCursor":"next","data":[{"id":1,"name":"Roblox","displayName":"Roblox"}]}"#,
+		),
+		MockResponse::json(
+			200,
+			r#"{"nextPageCursor":null,"data":[{"id":2,"name":"John","displayName":"John"}]}"#,
+		),
+	]);
+	let client = Robolt::from_async(robolt::Robolt::new().with_global_base_url(&server.url));
+	let users = client
+		.search_users("test", Limit::Min)
+		.into_iter()
+		.collect::<Result<Vec<_>, _>>()
+		.unwrap();
+
+	assert_eq!(users.len(), 2);
+}
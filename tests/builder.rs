@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use tokio_test::{assert_err, assert_ok};
+
+use common::{xcsrf_mock_response, MockResponse, MockServer, AUTHENTICATED_USER};
+use robolt::Robolt;
+
+mod common;
+
+#[tokio::test]
+async fn default_headers() {
+	let server = MockServer::start(vec![
+		MockResponse::json(200, AUTHENTICATED_USER),
+		xcsrf_mock_response(),
+		MockResponse::json(200, "{}"),
+	]);
+	let client = Robolt::builder()
+		.global_base_url(&server.url)
+		.user_agent("robolt-tests/1.0")
+		.accept_language("fr-FR")
+		.default_header("x-custom", "value")
+		.timeout(Duration::from_secs(5))
+		.build()
+		.unwrap()
+		.set_cookie("cookie".to_string())
+		.await
+		.unwrap();
+
+	assert_ok!(client.unfriend(1).await);
+
+	for request in server.requests() {
+		assert_eq!(request.header("user-agent"), Some("robolt-tests/1.0"));
+		assert_eq!(request.header("accept-language"), Some("fr-FR"));
+		assert_eq!(request.header("x-custom"), Some("value"));
+	}
+}
+
+#[test]
+fn invalid_header() {
+	assert_err!(Robolt::builder().default_header("invalid header", "value").build());
+}
@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use tokio_test::assert_ok;
+
+use common::user_transport;
+use robolt::api::routes::RobloxApi;
+use robolt::{CachePolicy, Robolt};
+
+mod common;
+
+#[tokio::test]
+async fn cached_reads() {
+	let transport = user_transport();
+	let client = Robolt::builder()
+		.transport(transport.clone())
+		.cache(CachePolicy::new())
+		.build()
+		.unwrap();
+
+	assert_eq!(assert_ok!(client.follower_count(1).await), 1);
+	assert_eq!(assert_ok!(client.clone().follower_count(1).await), 1);
+	assert_eq!(transport.requests().len(), 1);
+
+	client.invalidate_cache(RobloxApi::Friends, "/v1/users/1/followers/count");
+	assert_ok!(client.follower_count(1).await);
+	assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn endpoint_ttl() {
+	let transport = user_transport();
+	let policy = CachePolicy::new()
+		.domain_ttl(RobloxApi::Friends, Duration::from_millis(50))
+		.endpoint_ttl(RobloxApi::Friends, "/v1/users/{id}/followings/count", Duration::ZERO);
+	let client = Robolt::builder()
+		.transport(transport.clone())
+		.cache(policy)
+		.build()
+		.unwrap();
+
+	assert_ok!(client.following_count(1).await);
+	assert_ok!(client.following_count(1).await);
+	assert_eq!(transport.requests().len(), 2);
+
+	assert_ok!(client.follower_count(1).await);
+	assert_ok!(client.follower_count(1).await);
+	assert_eq!(transport.requests().len(), 3);
+
+	tokio::time::sleep(Duration::from_millis(60)).await;
+	assert_ok!(client.follower_count(1).await);
+	assert_eq!(transport.requests().len(), 4);
+}
+
+#[tokio::test]
+async fn lru_eviction() {
+	let transport = user_transport();
+	let client = Robolt::builder()
+		.transport(transport.clone())
+		.cache(CachePolicy::new().capacity(1))
+		.build()
+		.unwrap();
+
+	assert_ok!(client.follower_count(1).await);
+	assert_ok!(client.following_count(1).await);
+	assert_ok!(client.follower_count(1).await);
+	assert_eq!(transport.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn authenticated_requests() {
+	let transport = user_transport();
+	let client = Robolt::builder()
+		.transport(transport.clone())
+		.cache(CachePolicy::new())
+		.build()
+		.unwrap()
+		.set_cookie("cookie".to_string())
+		.await
+		.unwrap();
+
+	assert_ok!(client.follower_count(1).await);
+	assert_ok!(client.follower_count(1).await);
+	assert_eq!(transport.requests().len(), 4);
+
+	let client = client.with_cache(CachePolicy::new().cache_authenticated(true));
+	assert_ok!(client.follower_count(1).await);
+	assert_ok!(client.follower_count(1).await);
+	assert_eq!(transport.requests().len(), 5);
+}
@@ -0,0 +1,143 @@
+use std::env::temp_dir;
+use std::path::PathBuf;
+
+use reqwest::Method;
+use serde_json::{json, Value};
+use tokio_test::{assert_err, assert_ok};
+
+use common::{xcsrf_response, AUTHENTICATED_URL, AUTHENTICATED_USER, XCSRF_URL};
+use robolt::api::routes::RobloxApi;
+use robolt::cassette::Cassette;
+use robolt::errors::RoboltError;
+use robolt::transport::{HttpResponse, MockTransport};
+use robolt::Robolt;
+
+mod common;
+
+fn cassette_path(name: &str) -> PathBuf {
+	temp_dir().join(format!("robolt-{}-{name}.json", std::process::id()))
+}
+
+#[tokio::test]
+async fn record_and_replay() {
+	let path = cassette_path("record-and-replay");
+	let transport = MockTransport::new();
+	transport.respond(
+		Method::GET,
+		"https://friends.roblox.com/v1/users/1/followers/count",
+		200,
+		r#"{"count":42}"#,
+	);
+
+	let client = Robolt::builder().transport(transport).record(&path).build().unwrap();
+	assert_eq!(assert_ok!(client.follower_count(1).await), 42);
+
+	let client = Robolt::builder().replay(&path).build().unwrap();
+	assert_eq!(assert_ok!(client.follower_count(1).await), 42);
+
+	// Every interaction is only served once
+	let err = assert_err!(client.follower_count(1).await);
+	assert!(matches!(err, RoboltError::Transport(_)));
+
+	std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn redacts_cookie() {
+	let path = cassette_path("redacts-cookie");
+	let transport = MockTransport::new();
+	transport.respond_with(
+		Method::GET,
+		AUTHENTICATED_URL,
+		HttpResponse::new(200.try_into().unwrap(), AUTHENTICATED_USER)
+			.header("x-csrf-token", "token")
+			.header("set-cookie", ".ROBLOSECURITY=secret"),
+	);
+	transport.respond_with(Method::POST, XCSRF_URL, xcsrf_response());
+
+	let client = Robolt::builder().transport(transport).record(&path).build().unwrap();
+	assert_ok!(client.set_cookie("secret".to_string()).await);
+
+	let cassette = Cassette::load(&path).unwrap();
+	let contents = std::fs::read_to_string(&path).unwrap();
+	let interaction = &cassette.interactions[0];
+
+	assert!(!contents.contains("secret"));
+	assert_eq!(interaction.request.headers["cookie"], "[REDACTED]");
+	assert_eq!(interaction.response.headers["x-csrf-token"], "[REDACTED]");
+	assert_eq!(cassette.interactions[1].response.headers["x-csrf-token"], "[REDACTED]");
+
+	std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn redacts_secret_fields() {
+	let path = cassette_path("redacts-secret-fields");
+	let url = "https://apis.roblox.com/reauthentication-service/v1/token/generate";
+	let transport = MockTransport::new();
+	transport.respond(Method::POST, url, 200, r#"{"token":"issued","expires":60}"#);
+
+	let generate = |client: Robolt| async move {
+		client
+			.request(RobloxApi::Challenge, "/reauthentication-service/v1/token/generate")
+			.method(Method::POST)
+			.send_body::<_, Value>(json!({ "password": "hunter2", "username": "Roblox" }))
+			.await
+	};
+
+	let client = Robolt::builder().transport(transport).record(&path).build().unwrap();
+	assert_eq!(assert_ok!(generate(client).await)["token"], "issued");
+
+	let contents = std::fs::read_to_string(&path).unwrap();
+	let interaction = &Cassette::load(&path).unwrap().interactions[0];
+	assert!(!contents.contains("hunter2"));
+	assert!(!contents.contains("issued"));
+	assert!(interaction.request.body.as_ref().unwrap().contains("Roblox"));
+	assert!(interaction.response.body.contains("60"));
+
+	// Requests are redacted the same way when replayed, so they still match
+	let client = Robolt::builder().replay(&path).build().unwrap();
+	assert_eq!(assert_ok!(generate(client).await)["token"], "[REDACTED]");
+
+	std::fs::remove_file(path).unwrap();
+}
+
+#[cfg(feature = "oauth")]
+#[tokio::test]
+async fn redacts_secret_form_fields() {
+	use robolt::oauth::OAuthConfig;
+
+	let path = cassette_path("redacts-secret-form-fields");
+	let transport = MockTransport::new();
+	transport.respond(
+		Method::POST,
+		"https://apis.roblox.com/oauth/v1/token",
+		200,
+		r#"{"access_token":"access","refresh_token":"refresh","expires_in":900}"#,
+	);
+
+	let config = OAuthConfig::new("client", "http://localhost/callback").client_secret("secret");
+	let client = Robolt::builder().transport(transport).record(&path).build().unwrap();
+	assert_ok!(client.exchange_code(&config, "code", "verifier").await);
+
+	let interaction = &Cassette::load(&path).unwrap().interactions[0];
+	let body = interaction.request.body.as_ref().unwrap();
+	assert!(body.contains("client_id=client"));
+	assert!(body.contains("client_secret=%5BREDACTED%5D"));
+	assert!(!body.contains("=verifier"));
+	assert!(!interaction.response.body.contains("refresh\""));
+
+	std::fs::remove_file(path).unwrap();
+}
+
+#[tokio::test]
+async fn unmatched_request() {
+	let path = cassette_path("unmatched-request");
+	Cassette::default().save(&path).unwrap();
+
+	let client = Robolt::builder().replay(&path).build().unwrap();
+	let err = assert_err!(client.user(1).await);
+	assert!(matches!(err, RoboltError::Transport(_)));
+
+	std::fs::remove_file(path).unwrap();
+}
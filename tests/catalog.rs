@@ -26,7 +26,7 @@ async fn bundle_recommendations() {
 async fn bundles() {
 	let client = Robolt::new();
 	let bundle_ids = vec![1, 2, 3];
-	assert_ok!(client.bundles(bundle_ids).await);
+	assert_ok!(client.bundles(bundle_ids).await.into_result());
 }
 
 #[tokio::test]
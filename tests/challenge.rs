@@ -0,0 +1,204 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use reqwest::{Method, StatusCode};
+use serde_json::{json, Value};
+use tokio_test::{assert_err, assert_ok};
+
+use common::authenticated_transport;
+use robolt::api::routes::RobloxApi;
+use robolt::challenge::{ChallengeType, TwoStepVerificationMedia};
+use robolt::errors::RoboltError;
+use robolt::transport::{HttpResponse, MockTransport};
+use robolt::Robolt;
+
+mod common;
+
+const UNFOLLOW_URL: &str = "https://friends.roblox.com/v1/users/2/unfollow";
+const CONTINUE_URL: &str = "https://apis.roblox.com/challenge/v1/continue";
+const TWO_STEP_METADATA: &str = "eyJ1c2VySWQiOiIxIiwiY2hhbGxlbmdlSWQiOiJpbm5lciIsImFjdGlvblR5cGUiOiJHZW5lcmljIn0=";
+
+fn challenged(kind: &str, metadata: &str) -> MockTransport {
+	let transport = authenticated_transport();
+	transport.respond_with(
+		Method::POST,
+		UNFOLLOW_URL,
+		HttpResponse::new(
+			403.try_into().unwrap(),
+			r#"{"errors":[{"code":0,"message":"Challenge is required"}]}"#,
+		)
+		.header("rblx-challenge-id", "challenge")
+		.header("rblx-challenge-type", kind)
+		.header("rblx-challenge-metadata", metadata),
+	);
+	transport.respond(Method::POST, UNFOLLOW_URL, 200, "{}");
+	transport.respond(Method::POST, CONTINUE_URL, 200, "{}");
+	transport
+}
+
+#[tokio::test]
+async fn two_step_verification() {
+	let transport = challenged("twostepverification", TWO_STEP_METADATA);
+	transport.respond(
+		Method::POST,
+		"https://twostepverification.roblox.com/v1/users/1/challenges/authenticator/verify",
+		200,
+		r#"{"verificationToken":"token"}"#,
+	);
+
+	let client = Robolt::builder()
+		.transport(transport.clone())
+		.build()
+		.unwrap()
+		.set_cookie("cookie".to_string())
+		.await
+		.unwrap();
+	let RoboltError::Challenge(challenge) = assert_err!(client.unfollow(2).await) else {
+		panic!("Expected a challenge");
+	};
+
+	assert_eq!(challenge.kind, ChallengeType::TwoStepVerification);
+	assert_eq!(challenge.status, StatusCode::FORBIDDEN);
+	assert_eq!(challenge.metadata["challengeId"], "inner");
+
+	let metadata = challenge.two_step_verification().unwrap();
+	assert_eq!(metadata.user_id, 1);
+
+	let solution = assert_ok!(
+		client
+			.solve_two_step_verification(&challenge, TwoStepVerificationMedia::Authenticator, "123456")
+			.await
+	);
+	let verify = transport.last_request().unwrap();
+	assert_eq!(
+		verify.json::<Value>().unwrap().unwrap(),
+		json!({ "challengeId": "inner", "actionType": "Generic", "code": "123456" })
+	);
+
+	assert_ok!(client.resubmit::<Value>(&challenge, &solution).await);
+
+	let requests = transport.requests();
+	let (completed, resubmitted) = (&requests[requests.len() - 2], &requests[requests.len() - 1]);
+	let continued = completed.json::<Value>().unwrap().unwrap();
+	assert_eq!(completed.url, CONTINUE_URL);
+	assert_eq!(continued["challengeType"], "twostepverification");
+
+	assert_eq!(resubmitted.url, UNFOLLOW_URL);
+	assert_eq!(resubmitted.header("rblx-challenge-id"), Some("challenge"));
+	assert_eq!(resubmitted.header("rblx-challenge-type"), Some("twostepverification"));
+
+	let metadata = STANDARD
+		.decode(resubmitted.header("rblx-challenge-metadata").unwrap())
+		.unwrap();
+	let metadata = serde_json::from_slice::<Value>(&metadata).unwrap();
+	assert_eq!(metadata["verificationToken"], "token");
+	assert_eq!(metadata["challengeId"], "inner");
+}
+
+#[tokio::test]
+async fn reauthentication() {
+	let transport = challenged("reauthentication", "e30=");
+	transport.respond(
+		Method::POST,
+		"https://apis.roblox.com/reauthentication-service/v1/token/generate",
+		200,
+		r#"{"token":"token"}"#,
+	);
+
+	let client = Robolt::builder()
+		.transport(transport.clone())
+		.build()
+		.unwrap()
+		.set_cookie("cookie".to_string())
+		.await
+		.unwrap();
+	let RoboltError::Challenge(challenge) = assert_err!(client.unfollow(2).await) else {
+		panic!("Expected a challenge");
+	};
+
+	assert!(challenge.two_step_verification().is_none());
+
+	let solution = assert_ok!(client.solve_reauthentication(&challenge, "password").await);
+	assert_eq!(solution.metadata, json!({ "reauthenticationToken": "token" }));
+	assert_ok!(client.resubmit::<Value>(&challenge, &solution).await);
+	assert_eq!(
+		transport.last_request().unwrap().header("rblx-challenge-type"),
+		Some("reauthentication")
+	);
+}
+
+#[tokio::test]
+async fn unknown_challenge() {
+	let transport = challenged("proofofwork", "not base64");
+	let client = Robolt::builder()
+		.transport(transport.clone())
+		.build()
+		.unwrap()
+		.set_cookie("cookie".to_string())
+		.await
+		.unwrap();
+
+	let RoboltError::Challenge(challenge) = assert_err!(client.unfollow(2).await) else {
+		panic!("Expected a challenge");
+	};
+
+	assert_eq!(challenge.kind, ChallengeType::Unknown("proofofwork".to_string()));
+	assert_eq!(challenge.metadata, Value::Null);
+	assert!(matches!(
+		client
+			.solve_two_step_verification(&challenge, TwoStepVerificationMedia::Email, "123456")
+			.await,
+		Err(RoboltError::Validation(_))
+	));
+}
+
+#[tokio::test]
+async fn redacted_body() {
+	let transport = challenged("captcha", "e30=");
+	let client = Robolt::builder()
+		.transport(transport)
+		.build()
+		.unwrap()
+		.set_cookie("cookie".to_string())
+		.await
+		.unwrap();
+
+	let err = client
+		.request(RobloxApi::Friends, "/v1/users/2/unfollow")
+		.method(Method::POST)
+		.send_body::<_, Value>(json!({ "password": "hunter2" }))
+		.await;
+	let RoboltError::Challenge(challenge) = assert_err!(err) else {
+		panic!("Expected a challenge");
+	};
+
+	let debug = format!("{challenge:?}");
+	assert!(debug.contains("/v1/users/2/unfollow"));
+	assert!(debug.contains(r#"body: Some("[REDACTED]")"#));
+}
+
+#[tokio::test]
+async fn missing_token() {
+	let transport = challenged("reauthentication", "e30=");
+	transport.respond(
+		Method::POST,
+		"https://apis.roblox.com/reauthentication-service/v1/token/generate",
+		200,
+		"{}",
+	);
+
+	let client = Robolt::builder()
+		.transport(transport)
+		.build()
+		.unwrap()
+		.set_cookie("cookie".to_string())
+		.await
+		.unwrap();
+	let RoboltError::Challenge(challenge) = assert_err!(client.unfollow(2).await) else {
+		panic!("Expected a challenge");
+	};
+
+	assert!(matches!(
+		client.solve_reauthentication(&challenge, "password").await,
+		Err(RoboltError::Decode { .. })
+	));
+}
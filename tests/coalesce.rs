@@ -0,0 +1,83 @@
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use tokio_test::assert_ok;
+
+use common::user_transport;
+use robolt::errors::RoboltError;
+use robolt::transport::{HttpRequest, HttpResponse, MockTransport, Transport};
+use robolt::{Coalescing, Robolt};
+
+mod common;
+
+#[derive(Debug)]
+/// Holds every response back so that concurrent requests overlap
+struct SlowTransport(MockTransport);
+
+impl Transport for SlowTransport {
+	fn send(&self, req: HttpRequest) -> BoxFuture<'_, Result<HttpResponse, RoboltError>> {
+		Box::pin(async move {
+			tokio::time::sleep(Duration::from_millis(100)).await;
+			self.0.send(req).await
+		})
+	}
+}
+
+#[tokio::test(start_paused = true)]
+async fn merges_identical_reads() {
+	let transport = user_transport();
+	let client = Robolt::builder()
+		.transport(SlowTransport(transport.clone()))
+		.build()
+		.unwrap();
+
+	let other = client.clone();
+
+	// Clones of a client share the requests in flight
+	let (first, second) = futures::join!(client.user(1), other.user(1));
+	assert_eq!(assert_ok!(first).id, 1);
+	assert_eq!(assert_ok!(second).id, 1);
+	assert_eq!(transport.requests().len(), 1);
+
+	// Later requests are sent again
+	assert_ok!(client.user(1).await);
+	assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn disabled() {
+	let transport = user_transport();
+	let client = Robolt::builder()
+		.transport(SlowTransport(transport.clone()))
+		.coalescing(Coalescing::Disabled)
+		.build()
+		.unwrap();
+
+	let (first, second) = futures::join!(client.user(1), client.user(1));
+	assert_ok!(first);
+	assert_ok!(second);
+	assert_eq!(transport.requests().len(), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn authenticated_reads() {
+	let transport = user_transport();
+	let client = Robolt::builder()
+		.transport(SlowTransport(transport.clone()))
+		.build()
+		.unwrap()
+		.set_cookie("cookie".to_string())
+		.await
+		.unwrap();
+
+	let (first, second) = futures::join!(client.user(1), client.user(1));
+	assert_ok!(first);
+	assert_ok!(second);
+	assert_eq!(transport.requests().len(), 4);
+
+	let client = client.with_coalescing(Coalescing::AllReads);
+	let (first, second) = futures::join!(client.user(1), client.user(1));
+	assert_ok!(first);
+	assert_ok!(second);
+	assert_eq!(transport.requests().len(), 5);
+}
@@ -0,0 +1,176 @@
+#![allow(dead_code)]
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use reqwest::Method;
+use robolt::transport::{HttpResponse, MockTransport};
+
+pub const AUTHENTICATED_URL: &str = "https://users.roblox.com/v1/users/authenticated";
+pub const AUTHENTICATED_USER: &str = r#"{"id":1,"name":"Roblox","displayName":"Roblox"}"#;
+pub const XCSRF_URL: &str = "https://auth.roblox.com/v1/usernames/validate";
+pub const FOLLOWER_COUNT_URL: &str = "https://friends.roblox.com/v1/users/1/followers/count";
+pub const FOLLOWING_COUNT_URL: &str = "https://friends.roblox.com/v1/users/1/followings/count";
+pub const USER_URL: &str = "https://users.roblox.com/v1/users/1";
+pub const USER: &str = r#"{"description":"","created":"2006-02-27T21:06:40.3Z","isBanned":false,"hasVerifiedBadge":true,"id":1,"name":"Roblox","displayName":"Roblox"}"#;
+
+/// A canned response served by [MockServer]
+pub struct MockResponse {
+	pub status: u16,
+	pub headers: Vec<(&'static str, &'static str)>,
+	pub body: &'static str,
+}
+
+impl MockResponse {
+	pub fn json(status: u16, body: &'static str) -> Self {
+		Self {
+			status,
+			headers: Vec::new(),
+			body,
+		}
+	}
+
+	pub fn header(mut self, name: &'static str, value: &'static str) -> Self {
+		self.headers.push((name, value));
+		self
+	}
+}
+
+/// A [MockTransport] answering the requests made when logging in, so that
+/// the client can be authenticated with any cookie and gets the `token` CSRF
+/// token
+pub fn authenticated_transport() -> MockTransport {
+	let transport = MockTransport::new();
+	transport.respond(Method::GET, AUTHENTICATED_URL, 200, AUTHENTICATED_USER);
+	transport.respond_with(Method::POST, XCSRF_URL, xcsrf_response());
+	transport
+}
+
+/// An [authenticated_transport] also answering the reads of user 1: its
+/// details, its follower count (1) and its following count (2)
+pub fn user_transport() -> MockTransport {
+	let transport = authenticated_transport();
+	transport.respond(Method::GET, FOLLOWER_COUNT_URL, 200, r#"{"count":1}"#);
+	transport.respond(Method::GET, FOLLOWING_COUNT_URL, 200, r#"{"count":2}"#);
+	transport.respond(Method::GET, USER_URL, 200, USER);
+	transport
+}
+
+/// The rejection carrying the `token` CSRF token
+pub fn xcsrf_response() -> HttpResponse {
+	HttpResponse::new(403.try_into().unwrap(), "{}").header("x-csrf-token", "token")
+}
+
+/// The [MockServer] counterpart of [xcsrf_response]
+pub fn xcsrf_mock_response() -> MockResponse {
+	MockResponse::json(403, "{}").header("x-csrf-token", "token")
+}
+
+/// A request received by [MockServer]
+#[derive(Debug, Clone)]
+pub struct ReceivedRequest {
+	pub method: String,
+	pub path: String,
+	pub headers: Vec<(String, String)>,
+	pub body: String,
+}
+
+impl ReceivedRequest {
+	pub fn header(&self, name: &str) -> Option<&str> {
+		self.headers
+			.iter()
+			.find(|(key, _)| key.eq_ignore_ascii_case(name))
+			.map(|(_, value)| value.as_str())
+	}
+}
+
+/// A local HTTP server answering each connection with the next queued
+/// response
+pub struct MockServer {
+	pub url: String,
+	requests: Arc<Mutex<Vec<ReceivedRequest>>>,
+}
+
+impl MockServer {
+	pub fn start(responses: Vec<MockResponse>) -> Self {
+		let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+		let url = format!("http://{}", listener.local_addr().unwrap());
+		let requests = Arc::new(Mutex::new(Vec::new()));
+		let received = requests.clone();
+
+		thread::spawn(move || {
+			for response in responses {
+				let (stream, _) = listener.accept().unwrap();
+				let mut reader = BufReader::new(stream);
+				let request = read_request(&mut reader);
+				received.lock().unwrap().push(request);
+
+				let mut stream = reader.into_inner();
+				let mut raw = format!(
+					"HTTP/1.1 {} Mock\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n",
+					response.status,
+					response.body.len()
+				);
+
+				for (name, value) in response.headers {
+					raw.push_str(&format!("{name}: {value}\r\n"));
+				}
+
+				raw.push_str("\r\n");
+				raw.push_str(response.body);
+				stream.write_all(raw.as_bytes()).unwrap();
+			}
+		});
+
+		Self {
+			url,
+			requests,
+		}
+	}
+
+	pub fn requests(&self) -> Vec<ReceivedRequest> {
+		self.requests.lock().unwrap().clone()
+	}
+}
+
+fn read_request<R: BufRead>(reader: &mut R) -> ReceivedRequest {
+	let mut line = String::new();
+	reader.read_line(&mut line).unwrap();
+
+	let mut parts = line.split_whitespace();
+	let method = parts.next().unwrap_or_default().to_string();
+	let path = parts.next().unwrap_or_default().to_string();
+	let mut headers = Vec::new();
+
+	loop {
+		let mut line = String::new();
+		reader.read_line(&mut line).unwrap();
+		let line = line.trim_end();
+
+		if line.is_empty() {
+			break;
+		}
+
+		if let Some((name, value)) = line.split_once(':') {
+			headers.push((name.trim().to_string(), value.trim().to_string()));
+		}
+	}
+
+	let length = headers
+		.iter()
+		.find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+		.and_then(|(_, value)| value.parse::<usize>().ok())
+		.unwrap_or(0);
+
+	let mut body = vec![0; length];
+	reader.read_exact(&mut body).unwrap();
+
+	ReceivedRequest {
+		method,
+		path,
+		headers,
+		body: String::from_utf8_lossy(&body).to_string(),
+	}
+}
@@ -0,0 +1,107 @@
+use std::time::Duration;
+
+use common::{MockResponse, MockServer};
+use robolt::errors::RoboltError;
+use robolt::{RetryPolicy, Robolt};
+
+mod common;
+
+fn client(server: &MockServer) -> Robolt {
+	Robolt::new()
+		.with_global_base_url(&server.url)
+		.with_retry_policy(RetryPolicy::none())
+}
+
+#[tokio::test]
+async fn api_error() {
+	let server = MockServer::start(vec![MockResponse::json(
+		400,
+		r#"{"errors":[{"code":3,"message":"The user id is invalid.","userFacingMessage":"Something went wrong","field":"userId"}]}"#,
+	)]);
+
+	match client(&server).user(0).await {
+		Err(RoboltError::Api {
+			status,
+			errors,
+		}) => {
+			assert_eq!(status.as_u16(), 400);
+			assert_eq!(errors[0].code, 3);
+			assert_eq!(errors[0].message, "The user id is invalid.");
+			assert_eq!(errors[0].user_facing_message.as_deref(), Some("Something went wrong"));
+			assert_eq!(errors[0].field.as_deref(), Some("userId"));
+		},
+		res => panic!("unexpected result: {res:?}"),
+	}
+}
+
+#[tokio::test]
+async fn unauthorized() {
+	let server = MockServer::start(vec![MockResponse::json(
+		401,
+		r#"{"errors":[{"code":0,"message":"Authorization has been denied for this request."}]}"#,
+	)]);
+
+	let err = client(&server).friend_count(1).await.unwrap_err();
+	assert!(matches!(err, RoboltError::Unauthorized { .. }));
+	assert_eq!(err.api_errors().len(), 1);
+}
+
+#[tokio::test]
+async fn rate_limited() {
+	let server = MockServer::start(vec![MockResponse::json(
+		429,
+		r#"{"errors":[{"code":0,"message":"Too many requests"}]}"#,
+	)
+	.header("retry-after", "7")]);
+
+	match client(&server).friend_count(1).await {
+		Err(RoboltError::RateLimited {
+			retry_after, ..
+		}) => assert_eq!(retry_after, Some(Duration::from_secs(7))),
+		res => panic!("unexpected result: {res:?}"),
+	}
+}
+
+#[tokio::test]
+async fn http_error() {
+	let server = MockServer::start(vec![MockResponse::json(500, "Internal Server Error")]);
+
+	match client(&server).friend_count(1).await {
+		Err(RoboltError::Http {
+			status,
+			body,
+		}) => {
+			assert_eq!(status.as_u16(), 500);
+			assert_eq!(body, "Internal Server Error");
+		},
+		res => panic!("unexpected result: {res:?}"),
+	}
+}
+
+#[tokio::test]
+async fn decode_error() {
+	let server = MockServer::start(vec![MockResponse::json(200, r#"{"total":1}"#)]);
+
+	match client(&server).friend_count(1).await {
+		Err(RoboltError::Decode {
+			body, ..
+		}) => assert_eq!(body, r#"{"total":1}"#),
+		res => panic!("unexpected result: {res:?}"),
+	}
+}
+
+#[tokio::test]
+async fn rate_limited_http_date() {
+	let server = MockServer::start(vec![MockResponse::json(
+		429,
+		r#"{"errors":[{"code":0,"message":"Too many requests"}]}"#,
+	)
+	.header("retry-after", "Wed, 21 Oct 2015 07:28:00 GMT")]);
+
+	match client(&server).friend_count(1).await {
+		Err(RoboltError::RateLimited {
+			retry_after, ..
+		}) => assert_eq!(retry_after, Some(Duration::ZERO)),
+		res => panic!("unexpected result: {res:?}"),
+	}
+}
@@ -0,0 +1,39 @@
+use reqwest::Method;
+use tokio_test::assert_ok;
+
+use robolt::api::ids::{UniverseId, UserId};
+use robolt::transport::MockTransport;
+use robolt::Robolt;
+
+#[tokio::test]
+async fn points_url() {
+	let transport = MockTransport::new();
+	transport.respond(
+		Method::GET,
+		"https://points.roblox.com/v1/universes/2/users/1/all-time",
+		200,
+		r#"{"allTimeScore":5}"#,
+	);
+
+	let client = Robolt::builder().transport(transport).build().unwrap();
+	assert_eq!(assert_ok!(client.points(UserId(1), UniverseId(2)).await), 5);
+}
+
+#[tokio::test]
+async fn typed_models() {
+	let transport = MockTransport::new();
+	transport.respond(
+		Method::GET,
+		"https://users.roblox.com/v1/users/1",
+		200,
+		r#"{"created":"2006-02-27T21:06:40.3Z","isBanned":false,"hasVerifiedBadge":true,"id":1,"name":"Roblox","displayName":"Roblox"}"#,
+	);
+
+	let client = Robolt::builder().transport(transport).build().unwrap();
+	let user = assert_ok!(client.user(UserId::from(1)).await);
+
+	assert_eq!(user.id, UserId(1));
+	assert_eq!(user.id, 1);
+	assert_eq!(u64::from(user.id), 1);
+	assert_eq!(serde_json::to_string(&user.id).unwrap(), "1");
+}
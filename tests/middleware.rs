@@ -0,0 +1,121 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use reqwest::Method;
+use tokio_test::{assert_err, assert_ok};
+
+use common::authenticated_transport;
+use robolt::errors::RoboltError;
+use robolt::middleware::Middleware;
+use robolt::transport::{HttpRequest, HttpResponse, MockTransport};
+use robolt::Robolt;
+
+mod common;
+
+const FOLLOWER_COUNT_URL: &str = "https://friends.roblox.com/v1/users/1/followers/count";
+
+#[derive(Debug)]
+struct Log {
+	name: &'static str,
+	calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl Middleware for Log {
+	fn before_send(&self, request: &mut HttpRequest) -> Result<(), RoboltError> {
+		self.calls.lock().unwrap().push(format!("before {}", self.name));
+		request.headers.insert("x-middleware", self.name.parse().unwrap());
+		Ok(())
+	}
+
+	fn after_receive(
+		&self,
+		_request: &HttpRequest,
+		_response: &mut Result<HttpResponse, RoboltError>,
+		_elapsed: Duration,
+	) {
+		self.calls.lock().unwrap().push(format!("after {}", self.name));
+	}
+}
+
+#[derive(Debug)]
+struct RewriteErrors;
+
+impl Middleware for RewriteErrors {
+	fn after_receive(
+		&self,
+		_request: &HttpRequest,
+		response: &mut Result<HttpResponse, RoboltError>,
+		_elapsed: Duration,
+	) {
+		if matches!(response, Ok(res) if res.status.is_client_error()) {
+			*response = Err(RoboltError::Validation("rewritten".to_string()));
+		}
+	}
+}
+
+#[tokio::test]
+async fn ordered_hooks() {
+	let calls = Arc::new(Mutex::new(Vec::new()));
+	let transport = MockTransport::new();
+	transport.respond(Method::GET, FOLLOWER_COUNT_URL, 200, r#"{"count":1}"#);
+
+	let client = Robolt::builder()
+		.transport(transport.clone())
+		.middleware(Log {
+			name: "first",
+			calls: calls.clone(),
+		})
+		.build()
+		.unwrap()
+		.with_middleware(Log {
+			name: "second",
+			calls: calls.clone(),
+		});
+
+	assert_ok!(client.follower_count(1).await);
+	assert_eq!(*calls.lock().unwrap(), [
+		"before first",
+		"before second",
+		"after second",
+		"after first"
+	]);
+	assert_eq!(transport.last_request().unwrap().header("x-middleware"), Some("second"));
+}
+
+#[tokio::test]
+async fn rewrite_errors() {
+	let transport = MockTransport::new();
+	transport.respond(Method::GET, FOLLOWER_COUNT_URL, 400, "{}");
+
+	let client = Robolt::builder()
+		.transport(transport)
+		.middleware(RewriteErrors)
+		.build()
+		.unwrap();
+	let err = assert_err!(client.follower_count(1).await);
+
+	assert!(matches!(err, RoboltError::Validation(message) if message == "rewritten"));
+}
+
+#[tokio::test]
+async fn shared_across_states() {
+	let calls = Arc::new(Mutex::new(Vec::new()));
+	let transport = authenticated_transport();
+	transport.respond(Method::GET, FOLLOWER_COUNT_URL, 200, r#"{"count":1}"#);
+
+	let client = Robolt::builder()
+		.transport(transport)
+		.middleware(Log {
+			name: "log",
+			calls: calls.clone(),
+		})
+		.build()
+		.unwrap()
+		.set_cookie("cookie".to_string())
+		.await
+		.unwrap();
+
+	assert_ok!(client.follower_count(1).await);
+	assert_ok!(client.remove_cookie().follower_count(1).await);
+	assert_eq!(calls.lock().unwrap().len(), 8);
+}
@@ -0,0 +1,184 @@
+#![cfg(feature = "oauth")]
+
+use std::time::{Duration, SystemTime};
+
+use reqwest::Method;
+use tokio_test::{assert_err, assert_ok};
+
+use robolt::api::routes::RobloxApi;
+use robolt::errors::RoboltError;
+use robolt::oauth::{OAuthConfig, OAuthTokens, Pkce};
+use robolt::transport::MockTransport;
+use robolt::Robolt;
+
+const SERVER: &str = "http://127.0.0.1:9000";
+const TOKEN_URL: &str = "http://127.0.0.1:9000/oauth/v1/token";
+const USERINFO_URL: &str = "http://127.0.0.1:9000/oauth/v1/userinfo";
+const USERINFO: &str = r#"{"sub":"1","name":"Roblox","nickname":"Roblox","preferred_username":"Roblox","created_at":1141075200,"profile":"https://www.roblox.com/users/1/profile"}"#;
+
+fn config() -> OAuthConfig {
+	OAuthConfig::new("client", "http://localhost/callback").client_secret("secret")
+}
+
+fn tokens(access_token: &str, expires_at: SystemTime) -> OAuthTokens {
+	OAuthTokens {
+		access_token: access_token.to_string(),
+		refresh_token: Some("refresh".to_string()),
+		id_token: None,
+		scope: "openid profile".to_string(),
+		expires_at,
+	}
+}
+
+fn client(transport: &MockTransport) -> Robolt {
+	Robolt::builder()
+		.transport(transport.clone())
+		.base_url(RobloxApi::OAuth, SERVER)
+		.build()
+		.unwrap()
+}
+
+#[test]
+fn authorization_url() {
+	let pkce = Pkce::from_verifier("dBjftJeZ4CVP-mJ92K1qF8r6VcC7M-9bs5Hd2L8vXGQ".to_string());
+	assert_eq!(pkce.challenge, "O9QaLITtZDsZK48tpJRrKa5ql-NUJmW6fo5cGg5psvA");
+
+	let authorization = Robolt::new().authorization_url(&config(), &["openid", "profile"]);
+	let url = &authorization.url;
+
+	assert!(url.starts_with("https://apis.roblox.com/oauth/v1/authorize?client_id=client&"));
+	assert!(url.contains("redirect_uri=http%3A%2F%2Flocalhost%2Fcallback"));
+	assert!(url.contains("scope=openid+profile"));
+	assert!(url.contains(&format!("state={}", authorization.state)));
+	assert!(url.contains(&format!("code_challenge={}", authorization.pkce.challenge)));
+	assert!(url.contains("code_challenge_method=S256"));
+	assert_eq!(
+		Pkce::from_verifier(authorization.pkce.verifier).challenge,
+		authorization.pkce.challenge
+	);
+}
+
+#[tokio::test]
+async fn exchange_and_userinfo() {
+	let transport = MockTransport::new();
+	transport.respond(
+		Method::POST,
+		TOKEN_URL,
+		200,
+		r#"{"access_token":"access","refresh_token":"refresh","token_type":"Bearer","expires_in":900,"scope":"openid profile"}"#,
+	);
+	transport.respond(Method::GET, USERINFO_URL, 200, USERINFO);
+
+	let client = client(&transport);
+	let tokens = assert_ok!(client.exchange_code(&config(), "code", "verifier").await);
+	assert_eq!(tokens.access_token, "access");
+	assert!(!format!("{tokens:?}").contains("access\""));
+
+	let form = String::from_utf8(transport.last_request().unwrap().body.unwrap()).unwrap();
+	assert!(form.contains("grant_type=authorization_code"));
+	assert!(form.contains("code_verifier=verifier"));
+	assert!(form.contains("client_secret=secret"));
+
+	let client = client.set_oauth(config(), tokens);
+	let debug = format!("{client:?}");
+	assert!(!debug.contains("secret\""));
+	assert!(!debug.contains("refresh\""));
+
+	let user = assert_ok!(client.userinfo().await);
+	assert_eq!(user.user_id(), Some(1.into()));
+
+	let req = transport.last_request().unwrap();
+	assert_eq!(req.header("authorization"), Some("Bearer access"));
+	assert_eq!(req.header("cookie"), None);
+}
+
+#[tokio::test]
+async fn bearer_only_for_oauth_hosts() {
+	let transport = MockTransport::new();
+	transport.respond(Method::GET, "https://users.roblox.com/v1/users/1", 200, "{}");
+
+	let expires_at = SystemTime::now() + Duration::from_secs(900);
+	let client = client(&transport).set_oauth(config(), tokens("access", expires_at));
+	let _ = client.user(1).await;
+
+	let req = transport.last_request().unwrap();
+	assert_eq!(req.url, "https://users.roblox.com/v1/users/1");
+	assert_eq!(req.header("authorization"), None);
+}
+
+#[tokio::test]
+async fn refresh_when_expired() {
+	let transport = MockTransport::new();
+	transport.respond(
+		Method::POST,
+		TOKEN_URL,
+		200,
+		r#"{"access_token":"fresh","refresh_token":"rotated","expires_in":900}"#,
+	);
+	transport.respond(Method::GET, USERINFO_URL, 200, USERINFO);
+
+	let client = client(&transport).set_oauth(config(), tokens("stale", SystemTime::now()));
+	let clone = client.clone();
+
+	assert_ok!(client.userinfo().await);
+	assert_ok!(clone.userinfo().await);
+
+	let requests = transport.requests();
+	assert_eq!(requests.len(), 3);
+	assert!(String::from_utf8(requests[0].body.clone().unwrap())
+		.unwrap()
+		.contains("refresh_token=refresh"));
+	assert_eq!(requests[2].header("authorization"), Some("Bearer fresh"));
+	assert_eq!(clone.tokens().refresh_token.as_deref(), Some("rotated"));
+}
+
+#[tokio::test]
+async fn refresh_when_rejected() {
+	let transport = MockTransport::new();
+	transport.respond(
+		Method::POST,
+		TOKEN_URL,
+		200,
+		r#"{"access_token":"fresh","expires_in":900}"#,
+	);
+	transport.respond(Method::GET, USERINFO_URL, 401, "{}");
+	transport.respond(Method::GET, USERINFO_URL, 200, USERINFO);
+
+	let expires_at = SystemTime::now() + Duration::from_secs(900);
+	let client = client(&transport).set_oauth(config(), tokens("revoked", expires_at));
+
+	assert_ok!(client.userinfo().await);
+	assert_eq!(
+		transport.last_request().unwrap().header("authorization"),
+		Some("Bearer fresh")
+	);
+	assert_eq!(client.tokens().refresh_token.as_deref(), Some("refresh"));
+}
+
+#[tokio::test]
+async fn token_errors() {
+	let transport = MockTransport::new();
+	transport.respond(
+		Method::POST,
+		TOKEN_URL,
+		400,
+		r#"{"error":"invalid_grant","error_description":"The code has expired"}"#,
+	);
+
+	let err = assert_err!(client(&transport).exchange_code(&config(), "code", "verifier").await);
+	assert!(matches!(err, RoboltError::OAuth { error, .. } if error == "invalid_grant"));
+}
+
+#[tokio::test]
+async fn revoke() {
+	let transport = MockTransport::new();
+	transport.respond(Method::POST, "http://127.0.0.1:9000/oauth/v1/token/revoke", 200, "");
+
+	let expires_at = SystemTime::now() + Duration::from_secs(900);
+	let client = client(&transport).set_oauth(config(), tokens("access", expires_at));
+	let client = assert_ok!(client.revoke().await);
+
+	assert!(!client.is_authenticated());
+	let form = String::from_utf8(transport.last_request().unwrap().body.unwrap()).unwrap();
+	assert!(form.starts_with("token=refresh&client_id=client"));
+}
@@ -0,0 +1,124 @@
+use reqwest::{Method, StatusCode};
+use serde_json::{json, Value};
+use tokio_test::{assert_err, assert_ok};
+
+use robolt::errors::RoboltError;
+use robolt::transport::MockTransport;
+use robolt::Robolt;
+
+const ENTRY_URL: &str = "https://apis.roblox.com/datastores/v1/universes/1/standard-datastores/datastore/entries/entry?datastoreName=Players&entryKey=user%2F1";
+
+#[tokio::test]
+async fn api_key_header() {
+	let transport = MockTransport::new();
+	transport.respond(
+		Method::POST,
+		"https://apis.roblox.com/messaging-service/v1/universes/1/topics/announcements",
+		200,
+		"",
+	);
+
+	let client = Robolt::builder()
+		.transport(transport.clone())
+		.build()
+		.unwrap()
+		.set_api_key("key".to_string())
+		.unwrap();
+
+	assert!(!client.is_authenticated());
+	assert_ok!(client.publish_message(1, "announcements", "Hello").await);
+
+	let req = transport.last_request().unwrap();
+	assert_eq!(req.header("x-api-key"), Some("key"));
+	assert_eq!(req.header("cookie"), None);
+	assert_eq!(req.json::<Value>().unwrap().unwrap(), json!({ "message": "Hello" }));
+
+	// Only Open Cloud receives the API key
+	let _ = client.user(1).await;
+	assert_eq!(transport.last_request().unwrap().header("x-api-key"), None);
+
+	let client = client.remove_api_key();
+	let _ = client.user(1).await;
+	assert_eq!(transport.last_request().unwrap().header("x-api-key"), None);
+}
+
+#[tokio::test]
+async fn encoded_topic() {
+	let transport = MockTransport::new();
+	transport.respond(
+		Method::POST,
+		"https://apis.roblox.com/messaging-service/v1/universes/1/topics/a%2Fb%3Fc%23d%20e",
+		200,
+		"",
+	);
+
+	let client = Robolt::builder()
+		.transport(transport)
+		.build()
+		.unwrap()
+		.set_api_key("key".to_string())
+		.unwrap();
+
+	assert_ok!(client.publish_message(1, "a/b?c#d e", "Hello").await);
+}
+
+#[tokio::test]
+async fn datastore_entries() {
+	let transport = MockTransport::new();
+	transport.respond(Method::GET, ENTRY_URL, 200, r#"{"coins":10}"#);
+	transport.respond(
+		Method::POST,
+		ENTRY_URL,
+		200,
+		r#"{"version":"08DB","deleted":false,"contentLength":12,"createdTime":"2023-04-01T12:30:00Z","objectCreatedTime":"2023-04-01T12:30:00Z"}"#,
+	);
+
+	let client = Robolt::builder()
+		.transport(transport.clone())
+		.build()
+		.unwrap()
+		.set_api_key("key".to_string())
+		.unwrap();
+
+	let entry = assert_ok!(client.datastore_entry::<Value>(1, "Players", "user/1").await);
+	assert_eq!(entry["coins"], 10);
+
+	let version = assert_ok!(
+		client
+			.set_datastore_entry(1, "Players", "user/1", json!({ "coins": 20 }))
+			.await
+	);
+	assert_eq!(version.content_length, 12);
+	assert_eq!(
+		transport.last_request().unwrap().json::<Value>().unwrap().unwrap(),
+		json!({ "coins": 20 })
+	);
+}
+
+#[tokio::test]
+async fn insufficient_scope() {
+	let transport = MockTransport::new();
+	transport.respond(
+		Method::GET,
+		ENTRY_URL,
+		403,
+		r#"{"error":"INSUFFICIENT_SCOPE","message":"The API key does not have sufficient scope to perform this operation."}"#,
+	);
+
+	let client = Robolt::builder()
+		.transport(transport)
+		.build()
+		.unwrap()
+		.set_api_key("key".to_string())
+		.unwrap();
+
+	let err = assert_err!(client.datastore_entry::<Value>(1, "Players", "user/1").await);
+	assert!(matches!(err, RoboltError::InsufficientScope { .. }));
+	assert_eq!(err.status(), Some(StatusCode::FORBIDDEN));
+}
+
+#[test]
+fn invalid_api_key() {
+	let err = assert_err!(Robolt::new().set_api_key("line\nbreak".to_string()));
+	assert!(matches!(err, RoboltError::Validation(_)));
+}
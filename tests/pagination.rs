@@ -0,0 +1,78 @@
+use futures::TryStreamExt;
+
+use common::{MockResponse, MockServer};
+use robolt::api::Limit;
+use robolt::errors::RoboltError;
+use robolt::pagination::SortOrder;
+use robolt::Robolt;
+
+mod common;
+
+const FIRST_PAGE: &str = r#"{"previousPageCursor":null,"nextPageCursor":"a+b/c=","data":[{"id":1,"name":"Roblox","displayName":"Roblox"}]}"#;
+const LAST_PAGE: &str = r#"{"previousPageCursor":"a+b/c=","nextPageCursor":null,"data":[{"id":2,"name":"John","displayName":"John"}]}"#;
+
+#[tokio::test]
+async fn page() {
+	let server = MockServer::start(vec![MockResponse::json(200, FIRST_PAGE)]);
+	let client = Robolt::new().with_global_base_url(&server.url);
+	let page = client.search_users("a b", Limit::Low).page().await.unwrap();
+
+	assert_eq!(page.next_page_cursor.as_deref(), Some("a+b/c="));
+	assert_eq!(page.data[0].id, 1);
+	assert_eq!(server.requests()[0].path, "/v1/users/search?keyword=a+b&limit=25");
+}
+
+#[tokio::test]
+async fn first_page() {
+	let server = MockServer::start(vec![MockResponse::json(200, FIRST_PAGE)]);
+	let client = Robolt::new().with_global_base_url(&server.url);
+
+	assert_eq!(client.search_users("test", Limit::Min).await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn stream() {
+	let server = MockServer::start(vec![
+		MockResponse::json(200, FIRST_PAGE),
+		MockResponse::json(200, LAST_PAGE),
+	]);
+	let client = Robolt::new().with_global_base_url(&server.url);
+	let users = client
+		.search_users("test", Limit::Max)
+		.sort_order(SortOrder::Desc)
+		.into_stream()
+		.try_collect::<Vec<_>>()
+		.await
+		.unwrap();
+
+	let requests = server.requests();
+	assert_eq!(users.iter().map(|user| user.id).collect::<Vec<_>>(), vec![1, 2]);
+	assert_eq!(
+		requests[1].path,
+		"/v1/users/search?keyword=test&limit=100&sortOrder=Desc&cursor=a%2Bb%2Fc%3D"
+	);
+}
+
+#[tokio::test]
+async fn page_size() {
+	let server = MockServer::start(vec![MockResponse::json(200, FIRST_PAGE)]);
+	let client = Robolt::new().with_global_base_url(&server.url);
+
+	client
+		.search_users("test", Limit::Min)
+		.page_size(30)
+		.page()
+		.await
+		.unwrap();
+	assert_eq!(server.requests()[0].path, "/v1/users/search?keyword=test&limit=30");
+
+	for page_size in [0, 101] {
+		let err = client
+			.search_users("test", Limit::Min)
+			.page_size(page_size)
+			.page()
+			.await;
+		assert!(matches!(err, Err(RoboltError::Validation(_))));
+	}
+	assert_eq!(server.requests().len(), 1);
+}
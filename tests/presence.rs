@@ -6,12 +6,12 @@ use robolt::Robolt;
 async fn presences() {
 	let client = Robolt::new();
 	let user_ids = vec![1, 2, 3];
-	assert_ok!(client.presences(user_ids).await);
+	assert_ok!(client.presences(user_ids).await.into_result());
 }
 
 #[tokio::test]
 async fn partial_presences() {
 	let client = Robolt::new();
 	let user_ids = vec![1, 2, 3];
-	assert_ok!(client.partial_presences(user_ids).await);
+	assert_ok!(client.partial_presences(user_ids).await.into_result());
 }
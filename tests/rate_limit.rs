@@ -0,0 +1,54 @@
+use std::time::Duration;
+
+use tokio::time::Instant;
+use tokio_test::assert_ok;
+
+use common::user_transport;
+use robolt::api::routes::RobloxApi;
+use robolt::{Rate, RateLimitPolicy, Robolt};
+
+mod common;
+
+#[tokio::test(start_paused = true)]
+async fn domain_buckets() {
+	let policy = RateLimitPolicy::new().domain_rate(RobloxApi::Friends, Rate::per_second(2));
+	let client = Robolt::builder()
+		.transport(user_transport())
+		.rate_limit(policy)
+		.build()
+		.unwrap();
+	let start = Instant::now();
+
+	assert_ok!(client.follower_count(1).await);
+	assert_ok!(client.clone().follower_count(1).await);
+	assert_eq!(start.elapsed(), Duration::ZERO);
+
+	// Other domains are not throttled
+	let _ = client.user(1).await;
+	assert_eq!(start.elapsed(), Duration::ZERO);
+
+	assert_ok!(client.follower_count(1).await);
+	assert!(start.elapsed() >= Duration::from_millis(500));
+}
+
+#[tokio::test(start_paused = true)]
+async fn cookie_buckets() {
+	let policy = RateLimitPolicy::new()
+		.default_rate(Rate::per_minute(1))
+		.per_cookie(true);
+	let client = Robolt::builder()
+		.transport(user_transport())
+		.rate_limit(policy)
+		.build()
+		.unwrap();
+	let first = client.clone().set_cookie("first".to_string()).await.unwrap();
+	let second = client.set_cookie("second".to_string()).await.unwrap();
+	let start = Instant::now();
+
+	assert_ok!(first.follower_count(1).await);
+	assert_ok!(second.follower_count(1).await);
+	assert_eq!(start.elapsed(), Duration::ZERO);
+
+	assert_ok!(first.follower_count(1).await);
+	assert!(start.elapsed() >= Duration::from_secs(60));
+}
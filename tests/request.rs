@@ -0,0 +1,104 @@
+use reqwest::Method;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tokio_test::assert_ok;
+
+use common::authenticated_transport;
+use robolt::api::routes::RobloxApi;
+use robolt::errors::RoboltError;
+use robolt::transport::MockTransport;
+use robolt::{RetryPolicy, Robolt};
+
+mod common;
+
+#[derive(Debug, Deserialize)]
+struct Games {
+	data: Vec<Game>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Game {
+	name: String,
+}
+
+fn mock() -> MockTransport {
+	let transport = authenticated_transport();
+	transport.respond(
+		Method::GET,
+		"https://games.roblox.com/v1/games?universeIds=1%2C2",
+		200,
+		r#"{"data":[{"name":"First"},{"name":"Second"}]}"#,
+	);
+	transport.respond(
+		Method::POST,
+		"https://games.roblox.com/v1/games/1/votes",
+		200,
+		r#"{"upVotes":1}"#,
+	);
+	transport.respond(Method::GET, "https://games.roblox.com/v1/games/3", 404, "{}");
+	transport
+}
+
+#[tokio::test]
+async fn typed_and_raw() {
+	let client = Robolt::builder().transport(mock()).build().unwrap();
+
+	let games = assert_ok!(
+		client
+			.request(RobloxApi::Custom("games.roblox.com"), "/v1/games")
+			.query("universeIds", "1,2")
+			.send::<Value>()
+			.await
+	);
+	assert_eq!(games["data"][1]["name"], "Second");
+
+	let games = assert_ok!(
+		client
+			.request(RobloxApi::Custom("games.roblox.com"), "/v1/games")
+			.query("universeIds", "1,2")
+			.send::<Games>()
+			.await
+	);
+	assert_eq!(games.data[0].name, "First");
+}
+
+#[tokio::test]
+async fn authenticated_body() {
+	let transport = mock();
+	let client = Robolt::builder()
+		.transport(transport.clone())
+		.build()
+		.unwrap()
+		.set_cookie("cookie".to_string())
+		.await
+		.unwrap();
+
+	let votes = assert_ok!(
+		client
+			.request(RobloxApi::Custom("games.roblox.com"), "/v1/games/1/votes")
+			.method(Method::POST)
+			.send_body::<_, Value>(json!({ "vote": true }))
+			.await
+	);
+	assert_eq!(votes["upVotes"], 1);
+
+	let req = transport.last_request().unwrap();
+	assert!(req.header("cookie").unwrap().contains("cookie"));
+	assert_eq!(req.header("x-csrf-token"), Some("token"));
+	assert_eq!(req.json::<Value>().unwrap().unwrap(), json!({ "vote": true }));
+}
+
+#[tokio::test]
+async fn error_mapping() {
+	let client = Robolt::builder()
+		.transport(mock())
+		.retry_policy(RetryPolicy::none())
+		.build()
+		.unwrap();
+	let res = client
+		.request(RobloxApi::Custom("games.roblox.com"), "/v1/games/3")
+		.send::<Value>()
+		.await;
+
+	assert!(matches!(res, Err(RoboltError::Http { .. })));
+}
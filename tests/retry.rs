@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+use tokio_test::{assert_err, assert_ok};
+
+use common::{MockResponse, MockServer};
+use robolt::{RetryPolicy, Robolt};
+
+mod common;
+
+fn policy() -> RetryPolicy {
+	RetryPolicy::new().base_delay(Duration::from_millis(1)).jitter(false)
+}
+
+#[tokio::test]
+async fn retry_server_error() {
+	let server = MockServer::start(vec![
+		MockResponse::json(503, r#"{"errors":[]}"#),
+		MockResponse::json(200, r#"{"count":1}"#),
+	]);
+	let client = Robolt::new()
+		.with_global_base_url(&server.url)
+		.with_retry_policy(policy());
+
+	assert_ok!(client.friend_count(1).await);
+	assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn retry_after() {
+	let server = MockServer::start(vec![
+		MockResponse::json(429, r#"{"errors":[]}"#).header("retry-after", "0"),
+		MockResponse::json(200, r#"{"userPresences":[]}"#),
+	]);
+	let client = Robolt::new()
+		.with_global_base_url(&server.url)
+		.with_retry_policy(policy());
+
+	assert_ok!(client.presences(vec![1]).await.into_result());
+	assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn no_retry_non_idempotent() {
+	let server = MockServer::start(vec![
+		MockResponse::json(500, r#"{"errors":[]}"#),
+		MockResponse::json(200, r#"{"userPresences":[]}"#),
+	]);
+	let client = Robolt::new()
+		.with_global_base_url(&server.url)
+		.with_retry_policy(policy());
+
+	assert_err!(client.presences(vec![1]).await.into_result());
+	assert_eq!(server.requests().len(), 1);
+}
+
+#[tokio::test]
+async fn max_attempts() {
+	let server = MockServer::start(vec![
+		MockResponse::json(503, r#"{"errors":[]}"#),
+		MockResponse::json(503, r#"{"errors":[]}"#),
+	]);
+	let client = Robolt::new()
+		.with_global_base_url(&server.url)
+		.with_retry_policy(policy().max_attempts(2));
+
+	assert_err!(client.friend_count(1).await);
+	assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn retry_after_clamped() {
+	let server = MockServer::start(vec![
+		MockResponse::json(429, r#"{"errors":[]}"#).header("retry-after", "86400"),
+		MockResponse::json(200, r#"{"count":1}"#),
+	]);
+	let client = Robolt::new()
+		.with_global_base_url(&server.url)
+		.with_retry_policy(policy().max_delay(Duration::from_millis(1)));
+
+	let res = tokio::time::timeout(Duration::from_secs(5), client.friend_count(1)).await;
+	assert_ok!(assert_ok!(res));
+	assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn retry_after_http_date() {
+	let server = MockServer::start(vec![
+		MockResponse::json(429, r#"{"errors":[]}"#).header("retry-after", "Wed, 21 Oct 2015 07:28:00 GMT"),
+		MockResponse::json(200, r#"{"count":1}"#),
+	]);
+	let client = Robolt::new()
+		.with_global_base_url(&server.url)
+		.with_retry_policy(policy().base_delay(Duration::from_secs(60)));
+
+	let res = tokio::time::timeout(Duration::from_secs(5), client.friend_count(1)).await;
+	assert_ok!(assert_ok!(res));
+	assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn ratelimit_reset_overflow() {
+	let server = MockServer::start(vec![
+		MockResponse::json(429, r#"{"errors":[]}"#)
+			.header("x-ratelimit-remaining", "0")
+			.header("x-ratelimit-reset", "1e20"),
+		MockResponse::json(200, r#"{"count":1}"#),
+	]);
+	let client = Robolt::new()
+		.with_global_base_url(&server.url)
+		.with_retry_policy(policy());
+
+	assert_ok!(client.friend_count(1).await);
+	assert_eq!(server.requests().len(), 2);
+}
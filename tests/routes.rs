@@ -0,0 +1,25 @@
+use tokio_test::assert_ok;
+
+use common::{MockResponse, MockServer};
+use robolt::api::routes::RobloxApi;
+use robolt::Robolt;
+
+mod common;
+
+#[tokio::test]
+async fn base_url() {
+	let server = MockServer::start(vec![MockResponse::json(200, r#"{"count":5}"#)]);
+	let client = Robolt::new().with_base_url(RobloxApi::Friends, &server.url);
+
+	assert_eq!(client.friend_count(1).await.unwrap(), 5);
+	assert_eq!(server.requests()[0].path, "/v1/users/1/friends/count");
+}
+
+#[tokio::test]
+async fn global_base_url() {
+	let server = MockServer::start(vec![MockResponse::json(200, r#"{"data":["Roblox"]}"#)]);
+	let client = Robolt::new().with_global_base_url(format!("{}/", server.url));
+
+	assert_ok!(client.username_history(1).await);
+	assert_eq!(server.requests()[0].path, "/v1/users/1/username-history");
+}
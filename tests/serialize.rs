@@ -0,0 +1,77 @@
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use robolt::api::account_settings::PrivacyState;
+use robolt::api::badges::Badge;
+use robolt::api::catalog::{AssetFavorite, AssetType, CatalogItemRestriction};
+use robolt::api::friends::FriendRequest;
+use robolt::api::presence::{UserPresence, UserPresenceType};
+use robolt::api::users::User;
+
+/// Asserts that the model serializes back to the exact JSON it was read from
+fn round_trip<T>(json: &str) -> T
+where
+	T: DeserializeOwned + Serialize,
+{
+	let model = serde_json::from_str::<T>(json).unwrap();
+	let serialized = serde_json::to_value(&model).unwrap();
+
+	assert_eq!(serialized, serde_json::from_str::<Value>(json).unwrap());
+	model
+}
+
+#[test]
+fn users() {
+	let user = round_trip::<User>(
+		r#"{"name":"Roblox","externalAppDisplayName":null,"description":"","created":"2006-02-27T21:06:40.3Z","isBanned":false,"hasVerifiedBadge":true,"id":1,"displayName":"Roblox"}"#,
+	);
+	assert_eq!(user.username, "Roblox");
+
+	round_trip::<FriendRequest>(
+		r#"{"name":"Roblox","externalAppDisplayName":null,"description":null,"created":"2006-02-27T21:06:40.3Z","isBanned":false,"hasVerifiedBadge":true,"id":1,"displayName":"Roblox","friendRequest":{"sentAt":"2023-04-01T12:30:00.123Z","senderId":1,"sourceUniverseId":null,"originSourceType":"UserProfile","contactName":null},"mutualFriendsList":[]}"#,
+	);
+}
+
+#[test]
+fn badges() {
+	round_trip::<Badge>(
+		r#"{"id":2124,"name":"Welcome","description":null,"displayName":"Welcome","displayDescription":null,"enabled":true,"iconImageId":1,"displayIconImageId":1,"created":"2019-01-01T00:00:00Z","updated":"2019-01-01T00:00:00Z","statistics":{"pastDayAwardedCount":1,"awardedCount":10,"winRatePercentage":0.5},"awardingUniverse":{"id":3,"name":"Place","rootPlaceId":4}}"#,
+	);
+}
+
+#[test]
+fn presences() {
+	round_trip::<UserPresence>(
+		r#"{"userPresenceType":2,"UserLocationType":1,"userId":1,"lastOnline":"2023-04-01T12:30:00.1234567Z","lastLocation":"Game","gameId":5,"placeId":4,"universeId":3,"rootPlaceId":4}"#,
+	);
+}
+
+#[test]
+fn catalog() {
+	let favorite = round_trip::<AssetFavorite>(r#"{"userId":1,"assetId":2,"created":"2023-04-01T12:30:00Z"}"#);
+	assert_eq!(favorite.asset_id, 2);
+}
+
+#[test]
+fn renamed_variants() {
+	let state = serde_json::from_str::<PrivacyState>(r#""All""#).unwrap();
+	assert_eq!(state, PrivacyState::Everyone);
+	assert_eq!(serde_json::to_string(&state).unwrap(), r#""AllUsers""#);
+	assert_eq!(round_trip::<PrivacyState>(r#""AllUsers""#), PrivacyState::Everyone);
+}
+
+#[test]
+fn unknown_variants() {
+	let presence = round_trip::<UserPresence>(
+		r#"{"userPresenceType":9,"UserLocationType":null,"userId":1,"lastOnline":"2023-04-01T12:30:00Z","lastLocation":"","gameId":null,"placeId":null,"universeId":null,"rootPlaceId":null}"#,
+	);
+	assert_eq!(presence.user_presence_type, UserPresenceType::Unknown(9));
+
+	let restriction = serde_json::from_str::<CatalogItemRestriction>(r#""Dynamic""#).unwrap();
+	assert_eq!(restriction, CatalogItemRestriction::Unknown("Dynamic".to_string()));
+	assert_eq!(serde_json::to_string(&restriction).unwrap(), r#""Dynamic""#);
+
+	assert_eq!(AssetType::from(2), AssetType::TShirt);
+	assert_eq!(u8::from(AssetType::Unknown(250)), 250);
+}
@@ -0,0 +1,107 @@
+use reqwest::Method;
+use tokio_test::assert_ok;
+
+use common::authenticated_transport;
+use robolt::errors::RoboltError;
+use robolt::session::Session;
+use robolt::transport::{HttpResponse, MockTransport};
+use robolt::Robolt;
+
+mod common;
+
+async fn exported() -> Session {
+	let transport = authenticated_transport();
+	let client = Robolt::builder()
+		.transport(transport.clone())
+		.build()
+		.unwrap()
+		.set_cookie("cookie".to_string())
+		.await
+		.unwrap();
+
+	// Exporting does not send anything, and describes the login rather than
+	// the export
+	let requests = transport.requests().len();
+	let session = client.session().unwrap();
+	assert_eq!(transport.requests().len(), requests);
+	assert_eq!(client.session().unwrap().created_at, session.created_at);
+
+	session
+}
+
+#[tokio::test]
+async fn export_and_restore() {
+	let session = exported().await;
+	assert_eq!(session.cookie, "cookie");
+	assert_eq!(session.xcsrf.as_deref(), Some("token"));
+	assert_eq!(session.user_id, 1);
+	assert!(!format!("{session:?}").contains("cookie\""));
+
+	let json = serde_json::to_string(&session).unwrap();
+	let session = serde_json::from_str::<Session>(&json).unwrap();
+
+	let transport = MockTransport::new();
+	transport.respond(
+		Method::POST,
+		"https://friends.roblox.com/v1/users/2/unfollow",
+		200,
+		"{}",
+	);
+
+	let client = Robolt::builder().transport(transport.clone()).build().unwrap();
+	let client = assert_ok!(client.restore(session.clone()));
+	assert!(client.is_authenticated());
+
+	// Restoring does not send anything
+	assert!(transport.requests().is_empty());
+	assert_eq!(assert_ok!(client.session()), session);
+
+	assert_ok!(client.unfollow(2).await);
+	let req = transport.last_request().unwrap();
+	assert_eq!(req.header("cookie"), Some(".ROBLOSECURITY=cookie"));
+	assert_eq!(req.header("x-csrf-token"), Some("token"));
+}
+
+#[tokio::test]
+async fn stale_csrf_token() {
+	let mut session = exported().await;
+	session.xcsrf = Some("stale".to_string());
+
+	let transport = MockTransport::new();
+	transport.respond_with(
+		Method::POST,
+		"https://friends.roblox.com/v1/users/2/unfollow",
+		HttpResponse::new(403.try_into().unwrap(), "{}").header("x-csrf-token", "fresh"),
+	);
+	transport.respond(
+		Method::POST,
+		"https://friends.roblox.com/v1/users/2/unfollow",
+		200,
+		"{}",
+	);
+
+	let client = Robolt::builder().transport(transport.clone()).build().unwrap();
+	let client = client.restore(session).unwrap();
+
+	assert_ok!(client.unfollow(2).await);
+	assert_eq!(transport.last_request().unwrap().header("x-csrf-token"), Some("fresh"));
+}
+
+#[tokio::test]
+async fn invalid_session() {
+	let mut session = exported().await;
+	session.cookie = "line\nbreak".to_string();
+
+	assert!(matches!(Robolt::new().restore(session), Err(RoboltError::Session(_))));
+}
+
+#[cfg(feature = "session-encryption")]
+#[tokio::test]
+async fn sealed() {
+	let session = exported().await;
+	let sealed = session.seal("passphrase").unwrap();
+
+	assert!(!sealed.contains("cookie"));
+	assert_eq!(Session::open(&sealed, "passphrase").unwrap(), session);
+	assert!(matches!(Session::open(&sealed, "wrong"), Err(RoboltError::Session(_))));
+}
@@ -0,0 +1,38 @@
+use robolt::api::presence::PartialUserPresence;
+
+#[test]
+fn raw_string() {
+	let presence =
+		serde_json::from_str::<PartialUserPresence>(r#"{"userId":1,"lastOnline":"2023-04-01T12:30:00.1234567Z"}"#)
+			.unwrap();
+
+	assert_eq!(presence.last_online, "2023-04-01T12:30:00.1234567Z");
+	assert_eq!(
+		serde_json::to_string(&presence.last_online).unwrap(),
+		r#""2023-04-01T12:30:00.1234567Z""#
+	);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn date_time() {
+	use chrono::{TimeZone, Timelike, Utc};
+	use robolt::api::timestamp::Timestamp;
+
+	let expected = Utc.with_ymd_and_hms(2023, 4, 1, 12, 30, 0).unwrap();
+
+	for raw in [
+		"2023-04-01T12:30:00Z",
+		"2023-04-01T12:30:00.3Z",
+		"2023-04-01T12:30:00.1234567Z",
+		"2023-04-01T14:30:00.123+02:00",
+		"2023-04-01T12:30:00.123",
+	] {
+		let date_time = Timestamp::from(raw.to_string()).date_time().unwrap();
+		assert_eq!(date_time.with_nanosecond(0).unwrap(), expected, "{raw}");
+	}
+
+	let timestamp = Timestamp::from("yesterday".to_string());
+	assert_eq!(timestamp.date_time(), None);
+	assert_eq!(timestamp.as_str(), "yesterday");
+}
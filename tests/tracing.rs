@@ -0,0 +1,116 @@
+#![cfg(feature = "tracing")]
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use reqwest::Method;
+use tokio_test::assert_ok;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use tracing_core::span::Current;
+
+use common::authenticated_transport;
+use robolt::Robolt;
+
+mod common;
+
+type Fields = HashMap<String, String>;
+
+/// Collects the fields recorded on every span and tracks the entered span
+#[derive(Default)]
+struct Collector {
+	next_id: AtomicU64,
+	spans: Arc<Mutex<HashMap<u64, Fields>>>,
+	metadata: Mutex<HashMap<u64, &'static Metadata<'static>>>,
+	entered: Mutex<Vec<Id>>,
+}
+
+struct FieldVisitor<'a>(&'a mut Fields);
+
+impl Visit for FieldVisitor<'_> {
+	fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+		self.0.insert(field.name().to_string(), format!("{value:?}"));
+	}
+}
+
+impl Subscriber for Collector {
+	fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+		true
+	}
+
+	fn new_span(&self, span: &Attributes<'_>) -> Id {
+		let id = self.next_id.fetch_add(1, Ordering::SeqCst) + 1;
+		let mut fields = Fields::new();
+		span.record(&mut FieldVisitor(&mut fields));
+		self.spans.lock().unwrap().insert(id, fields);
+		self.metadata.lock().unwrap().insert(id, span.metadata());
+		Id::from_u64(id)
+	}
+
+	fn record(&self, span: &Id, values: &Record<'_>) {
+		let mut spans = self.spans.lock().unwrap();
+		values.record(&mut FieldVisitor(spans.get_mut(&span.into_u64()).unwrap()));
+	}
+
+	fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+	fn event(&self, _event: &Event<'_>) {}
+
+	fn enter(&self, span: &Id) {
+		self.entered.lock().unwrap().push(span.clone());
+	}
+
+	fn exit(&self, _span: &Id) {
+		self.entered.lock().unwrap().pop();
+	}
+
+	fn current_span(&self) -> Current {
+		match self.entered.lock().unwrap().last() {
+			Some(id) => Current::new(id.clone(), self.metadata.lock().unwrap()[&id.into_u64()]),
+			None => Current::none(),
+		}
+	}
+}
+
+#[tokio::test]
+async fn request_span() {
+	let transport = authenticated_transport();
+	transport.respond(
+		Method::GET,
+		"https://friends.roblox.com/v1/users/1/followers/count",
+		200,
+		r#"{"count":1}"#,
+	);
+
+	let client = Robolt::builder()
+		.transport(transport)
+		.build()
+		.unwrap()
+		.set_cookie("cookie-secret".to_string())
+		.await
+		.unwrap();
+
+	let collector = Collector::default();
+	let spans = collector.spans.clone();
+	let _guard = tracing::subscriber::set_default(collector);
+
+	assert_ok!(client.follower_count(1).await);
+
+	let spans = spans.lock().unwrap();
+	let span = spans.values().next().unwrap();
+
+	assert_eq!(spans.len(), 1);
+	assert_eq!(span["domain"], "Friends");
+	assert_eq!(span["method"], "GET");
+	assert_eq!(span["path"], "/v1/users/{id}/followers/count");
+	assert_eq!(span["status"], "200");
+	assert_eq!(span["retries"], "0");
+	assert!(span.contains_key("duration_ms"));
+
+	for value in spans.values().flat_map(HashMap::values) {
+		assert!(!value.contains("secret"));
+	}
+}
@@ -0,0 +1,76 @@
+use reqwest::Method;
+use serde_json::{json, Value};
+use tokio_test::{assert_err, assert_ok};
+
+use common::authenticated_transport;
+use robolt::errors::RoboltError;
+use robolt::transport::MockTransport;
+use robolt::Robolt;
+
+mod common;
+
+#[tokio::test]
+async fn canned_responses() {
+	let transport = MockTransport::new();
+	transport.respond(
+		Method::GET,
+		"https://friends.roblox.com/v1/users/1/followers/count",
+		200,
+		r#"{"count":42}"#,
+	);
+
+	let client = Robolt::builder().transport(transport.clone()).build().unwrap();
+	let count = assert_ok!(client.follower_count(1).await);
+
+	assert_eq!(count, 42);
+	assert_eq!(transport.requests().len(), 1);
+	assert_eq!(transport.requests()[0].header("accept"), Some("application/json"));
+}
+
+#[tokio::test]
+async fn json_body() {
+	let transport = MockTransport::new();
+	transport.respond(Method::POST, "https://users.roblox.com/v1/users", 200, r#"{"data":[]}"#);
+
+	let client = Robolt::builder().transport(transport.clone()).build().unwrap();
+	assert_ok!(client.users_from_ids(vec![1, 2], true).await.into_result());
+
+	let request = transport.last_request().unwrap();
+	let body: Value = request.json().unwrap().unwrap();
+
+	assert_eq!(request.header("content-type"), Some("application/json"));
+	assert_eq!(body, json!({ "userIds": [1, 2], "excludeBannedUsers": true }));
+}
+
+#[tokio::test]
+async fn cookie_and_xcsrf() {
+	let transport = authenticated_transport();
+	transport.respond(
+		Method::POST,
+		"https://friends.roblox.com/v1/users/1/unfriend",
+		200,
+		"{}",
+	);
+
+	let client = Robolt::builder()
+		.transport(transport.clone())
+		.build()
+		.unwrap()
+		.set_cookie("cookie".to_string())
+		.await
+		.unwrap();
+
+	assert_ok!(client.unfriend(1).await);
+
+	let request = transport.last_request().unwrap();
+	assert_eq!(request.header("cookie"), Some(".ROBLOSECURITY=cookie"));
+	assert_eq!(request.header("x-csrf-token"), Some("token"));
+}
+
+#[tokio::test]
+async fn unmatched_request() {
+	let client = Robolt::builder().transport(MockTransport::new()).build().unwrap();
+	let err = assert_err!(client.follower_count(1).await);
+
+	assert!(matches!(err, RoboltError::Transport(_)));
+}
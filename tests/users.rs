@@ -30,7 +30,7 @@ async fn search_users() {
 #[tokio::test]
 async fn users_from_ids() {
 	let client = Robolt::new();
-	assert_ok!(client.users_from_ids(vec![1, 2, 3], false).await);
+	assert_ok!(client.users_from_ids(vec![1, 2, 3], false).await.into_result());
 }
 
 #[tokio::test]